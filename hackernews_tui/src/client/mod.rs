@@ -1,11 +1,14 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
 
 use anyhow::Context;
 use rayon::prelude::*;
 
 use model::*;
 // re-export
-pub use query::{StoryNumericFilters, StorySortMode};
+pub use query::{SearchFilters, SearchQuery, SearchTarget, StoryNumericFilters, StorySortMode};
 
 use crate::{prelude::*, utils::decode_html};
 
@@ -15,11 +18,19 @@ mod query;
 
 const HN_ALGOLIA_PREFIX: &str = "https://hn.algolia.com/api/v1";
 const HN_OFFICIAL_PREFIX: &str = "https://hacker-news.firebaseio.com/v0";
-const HN_SEARCH_QUERY_STRING: &str =
-    "tags=story&restrictSearchableAttributes=title,url&typoTolerance=false";
+const HN_SEARCH_QUERY_STRING: &str = "restrictSearchableAttributes=title,url&typoTolerance=false";
+const HN_TEXT_SEARCH_QUERY_STRING: &str =
+    "restrictSearchableAttributes=comment_text,story_text&typoTolerance=false";
 pub const HN_HOST_URL: &str = "https://news.ycombinator.com";
-pub const STORY_LIMIT: usize = 20;
-pub const SEARCH_LIMIT: usize = 15;
+/// the default number of stories in a page
+const DEFAULT_STORY_LIMIT: usize = 20;
+/// the default number of search results in a page
+const DEFAULT_SEARCH_LIMIT: usize = 15;
+/// the maximum number of items in a page (the maximum `hitsPerPage` of HN Algolia APIs)
+const MAX_PAGE_SIZE: usize = 1000;
+/// the maximum number of story IDs requested in a single HN Algolia API request,
+/// which keeps the request's URL and `tags` filter short
+const MAX_STORY_IDS_PER_REQUEST: usize = 100;
 
 static CLIENT: once_cell::sync::OnceCell<HNClient> = once_cell::sync::OnceCell::new();
 
@@ -27,6 +38,10 @@ static CLIENT: once_cell::sync::OnceCell<HNClient> = once_cell::sync::OnceCell::
 #[derive(Clone)]
 pub struct HNClient {
     client: ureq::Agent,
+    story_limit: usize,
+    search_limit: usize,
+    /// a cache of requested users, shared between clones of the client
+    user_cache: Arc<RwLock<HashMap<String, User>>>,
 }
 
 /// A macro to log the runtime of an expression
@@ -45,13 +60,54 @@ impl HNClient {
     /// Create a new Hacker News Client
     pub fn new() -> Result<HNClient> {
         let timeout = config::get_config().client_timeout;
+        let (story_limit, search_limit) = Self::get_page_sizes();
         Ok(HNClient {
             client: ureq::AgentBuilder::new()
                 .timeout(std::time::Duration::from_secs(timeout))
                 .build(),
+            story_limit,
+            search_limit,
+            user_cache: Arc::new(RwLock::new(HashMap::new())),
         })
     }
 
+    /// Get the number of stories and search results in a page.
+    ///
+    /// If not specified in the config, the page size is determined based on the terminal's height
+    /// so that a page fills the screen of a tall terminal.
+    fn get_page_sizes() -> (usize, usize) {
+        // the number of lines used by each item (story or search result)
+        const ITEM_HEIGHT: usize = 2;
+        // the number of lines used by the title bar, the footer and the search bar
+        const RESERVED_HEIGHT: usize = 5;
+
+        let fit_screen_size = cursive::backends::crossterm::crossterm::terminal::size()
+            .map(|(_, height)| (height as usize).saturating_sub(RESERVED_HEIGHT) / ITEM_HEIGHT)
+            .unwrap_or_default();
+
+        let config = config::get_config();
+        (
+            config
+                .story_limit
+                .unwrap_or_else(|| std::cmp::max(DEFAULT_STORY_LIMIT, fit_screen_size))
+                .clamp(1, MAX_PAGE_SIZE),
+            config
+                .search_limit
+                .unwrap_or_else(|| std::cmp::max(DEFAULT_SEARCH_LIMIT, fit_screen_size))
+                .clamp(1, MAX_PAGE_SIZE),
+        )
+    }
+
+    /// Get the number of stories in a page
+    pub fn story_limit(&self) -> usize {
+        self.story_limit
+    }
+
+    /// Get the number of search results in a page
+    pub fn search_limit(&self) -> usize {
+        self.search_limit
+    }
+
     /// Get data of a HN item based on its id then parse the data
     /// to a corresponding struct representing that item
     pub fn get_item_from_id<T>(&self, id: u32) -> Result<T>
@@ -197,6 +253,31 @@ impl HNClient {
         Ok(())
     }
 
+    /// Get a Hacker News user based on the user's username.
+    ///
+    /// A requested user is cached to avoid requesting the same user multiple times.
+    pub fn get_user(&self, username: &str) -> Result<User> {
+        if let Some(user) = self.user_cache.read().unwrap().get(username) {
+            return Ok(user.clone());
+        }
+
+        let request_url = format!("{HN_OFFICIAL_PREFIX}/user/{username}.json");
+        let user: User = log!(
+            self.client
+                .get(&request_url)
+                .call()?
+                .into_json::<UserResponse>()?,
+            format!("get user (username={username}) using {request_url}")
+        )
+        .into();
+
+        self.user_cache
+            .write()
+            .unwrap()
+            .insert(username.to_string(), user.clone());
+        Ok(user)
+    }
+
     /// Get a story based on its id
     pub fn get_story_from_story_id(&self, id: u32) -> Result<Story> {
         let request_url = format!("{HN_ALGOLIA_PREFIX}/search?tags=story,story_{id}");
@@ -217,30 +298,79 @@ impl HNClient {
     /// Get a list of stories matching certain conditions
     pub fn get_matched_stories(
         &self,
-        query: &str,
+        query: &SearchQuery,
         by_date: bool,
         page: usize,
     ) -> Result<Vec<Story>> {
         let request_url = format!(
-            "{}/{}?{}&hitsPerPage={}&page={}",
+            "{}/{}?tags={}&{}&hitsPerPage={}&page={}{}",
             HN_ALGOLIA_PREFIX,
             if by_date { "search_by_date" } else { "search" },
+            query.filters.tags(SearchTarget::Stories),
             HN_SEARCH_QUERY_STRING,
-            SEARCH_LIMIT,
-            page
+            self.search_limit,
+            page,
+            query.filters.numeric_filters.query(),
         );
+
+        // Algolia APIs don't support filtering by a site, so the site is added to the query's text
+        // to narrow down the search results. The results are then filtered by the site's domain.
+        let text = match &query.filters.site {
+            Some(site) => format!("{} {site}", query.text),
+            None => query.text.clone(),
+        };
+
         let response = log!(
             self.client
                 .get(&request_url)
-                .query("query", query)
+                .query("query", &text)
                 .call()?
                 .into_json::<StoriesResponse>()?,
             format!(
-                "get matched stories with query {query} (by_date={by_date}, page={page}) using {request_url}"
+                "get matched stories with query {text} (by_date={by_date}, page={page}) using {request_url}"
             )
         );
 
-        Ok(response.into())
+        let mut stories: Vec<Story> = response.into();
+        stories.retain(|story| query.filters.match_site(&story.url));
+        Ok(stories)
+    }
+
+    /// Get a list of comments (and stories' text) matching a search query
+    pub fn get_matched_texts(
+        &self,
+        query: &SearchQuery,
+        by_date: bool,
+        page: usize,
+    ) -> Result<Vec<TextMatch>> {
+        let request_url = format!(
+            "{}/{}?tags={}&{}&hitsPerPage={}&page={}{}",
+            HN_ALGOLIA_PREFIX,
+            if by_date { "search_by_date" } else { "search" },
+            query.filters.tags(SearchTarget::Comments),
+            HN_TEXT_SEARCH_QUERY_STRING,
+            self.search_limit,
+            page,
+            query.filters.numeric_filters.query(),
+        );
+        let text = &query.text;
+        let response = log!(
+            self.client
+                .get(&request_url)
+                .query("query", text)
+                .call()?
+                .into_json::<TextMatchesResponse>()?,
+            format!(
+                "get matched texts with query {text} (by_date={by_date}, page={page}) using {request_url}"
+            )
+        );
+
+        // unlike the story search, the site can't be added to the query's text
+        // because only the texts of comments and stories are searched,
+        // so only the matches in the page are filtered by the site's domain
+        let mut matches: Vec<TextMatch> = response.into();
+        matches.retain(|m| query.filters.match_site(&m.url));
+        Ok(matches)
     }
 
     /// Reorder a list of stories to follow the same order as another list of story IDs.
@@ -249,23 +379,15 @@ impl HNClient {
     /// reoder those stories to match the list shown up in the HackerNews website,
     /// which has the same order as the list of IDs returned from the official API.
     fn reorder_stories_based_on_ids(&self, stories: Vec<Story>, ids: &[u32]) -> Vec<Story> {
+        let positions = ids
+            .iter()
+            .enumerate()
+            .map(|(pos, id)| (*id, pos))
+            .collect::<HashMap<_, _>>();
+
         let mut stories = stories;
-        stories.sort_by(|story_x, story_y| {
-            let story_x_pos = ids
-                .iter()
-                .enumerate()
-                .find(|&(_, story_id)| *story_id == story_x.id)
-                .unwrap()
-                .0;
-            let story_y_pos = ids
-                .iter()
-                .enumerate()
-                .find(|&(_, story_id)| *story_id == story_y.id)
-                .unwrap()
-                .0;
-
-            story_x_pos.cmp(&story_y_pos)
-        });
+        // stories not in the list of IDs (if any) are placed at the end
+        stories.sort_by_key(|story| positions.get(&story.id).copied().unwrap_or(usize::MAX));
         stories
     }
 
@@ -295,33 +417,44 @@ impl HNClient {
             format!("get {tag} story IDs using {request_url}")
         );
 
-        let start_id = STORY_LIMIT * page;
+        let start_id = self.story_limit.saturating_mul(page);
         if start_id >= stories.len() {
             return Ok(vec![]);
         }
 
-        let end_id = std::cmp::min(start_id + STORY_LIMIT, stories.len());
+        let end_id = std::cmp::min(start_id.saturating_add(self.story_limit), stories.len());
         let ids = &stories[start_id..end_id];
 
-        let request_url = format!(
-            "{}/search?tags=story,({}){}&hitsPerPage={}",
-            HN_ALGOLIA_PREFIX,
-            ids.iter().fold("".to_owned(), |tags, story_id| format!(
-                "{tags}story_{story_id},"
-            )),
-            numeric_filters.query(),
-            STORY_LIMIT,
-        );
+        // split the IDs into chunks, each of which is requested separately
+        let stories = ids
+            .par_chunks(MAX_STORY_IDS_PER_REQUEST)
+            .map(|chunk| {
+                let request_url = format!(
+                    "{}/search?tags=story,({}){}&hitsPerPage={}",
+                    HN_ALGOLIA_PREFIX,
+                    chunk.iter().fold("".to_owned(), |tags, story_id| format!(
+                        "{tags}story_{story_id},"
+                    )),
+                    numeric_filters.query(),
+                    // the number of returned stories is at most the number of the requested IDs
+                    chunk.len(),
+                );
 
-        let response = log!(
-            self.client
-                .get(&request_url)
-                .call()?
-                .into_json::<StoriesResponse>()?,
-            format!("get stories (tag={tag}, page={page}) using {request_url}",)
-        );
+                let response = log!(
+                    self.client
+                        .get(&request_url)
+                        .call()?
+                        .into_json::<StoriesResponse>()?,
+                    format!("get stories (tag={tag}, page={page}) using {request_url}",)
+                );
+                Ok(Vec::<Story>::from(response))
+            })
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .collect();
 
-        Ok(self.reorder_stories_based_on_ids(response.into(), ids))
+        Ok(self.reorder_stories_based_on_ids(stories, ids))
     }
 
     /// Get a list of stories filtering on a specific tag.
@@ -348,7 +481,7 @@ impl HNClient {
             HN_ALGOLIA_PREFIX,
             search_op,
             tag,
-            STORY_LIMIT,
+            self.story_limit,
             page,
             numeric_filters.query(),
         );
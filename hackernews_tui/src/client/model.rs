@@ -29,6 +29,8 @@ struct MatchResult {
 #[derive(Debug, Deserialize)]
 struct HighlightResultResponse {
     title: Option<MatchResult>,
+    comment_text: Option<MatchResult>,
+    story_text: Option<MatchResult>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -59,6 +61,29 @@ pub struct StoryResponse {
     highlight_result: Option<HighlightResultResponse>,
 }
 
+#[derive(Debug, Deserialize)]
+/// TextMatchResponse represents a comment or a story matching a text search query,
+/// which is received from HN_ALGOLIA APIs
+pub struct TextMatchResponse {
+    #[serde(default)]
+    #[serde(rename(deserialize = "objectID"))]
+    #[serde(deserialize_with = "parse_id")]
+    id: u32,
+
+    author: Option<String>,
+    title: Option<String>,
+    url: Option<String>,
+    story_id: Option<u32>,
+    story_title: Option<String>,
+    story_url: Option<String>,
+
+    #[serde(rename(deserialize = "created_at_i"))]
+    time: u64,
+
+    #[serde(rename(deserialize = "_highlightResult"))]
+    highlight_result: Option<HighlightResultResponse>,
+}
+
 #[derive(Debug, Deserialize)]
 /// ItemResponse represents the item data received from the official HackerNews APIs
 pub struct ItemResponse {
@@ -109,6 +134,44 @@ impl From<StoriesResponse> for Vec<Story> {
     }
 }
 
+#[derive(Debug, Deserialize)]
+/// TextMatchesResponse represents the text search data received from HN_ALGOLIA APIs
+pub struct TextMatchesResponse {
+    pub hits: Vec<TextMatchResponse>,
+}
+
+impl From<TextMatchesResponse> for Vec<TextMatch> {
+    fn from(s: TextMatchesResponse) -> Vec<TextMatch> {
+        s.hits
+            .into_par_iter()
+            .filter_map(|hit| hit.try_into().ok())
+            .collect()
+    }
+}
+
+impl TryFrom<TextMatchResponse> for TextMatch {
+    type Error = anyhow::Error;
+
+    fn try_from(m: TextMatchResponse) -> Result<Self> {
+        // the matched text can be either a comment's text or a story's text
+        let content = m
+            .highlight_result
+            .and_then(|r| r.comment_text.or(r.story_text))
+            .map(|r| decode_html(&r.value))
+            .ok_or_else(|| anyhow::anyhow!("no matched text in item (id={})", m.id))?;
+
+        Ok(TextMatch {
+            id: m.id,
+            story_id: m.story_id.unwrap_or(m.id),
+            story_title: decode_html(&m.story_title.or(m.title).unwrap_or_default()),
+            url: m.story_url.or(m.url).unwrap_or_default(),
+            author: m.author.unwrap_or_default(),
+            time: m.time,
+            content,
+        })
+    }
+}
+
 impl From<StoryResponse> for Story {
     fn from(s: StoryResponse) -> Self {
         let title = s
@@ -163,3 +226,22 @@ impl From<CommentResponse> for Vec<Comment> {
         [vec![comment], children].concat()
     }
 }
+
+#[derive(Debug, Deserialize)]
+/// UserResponse represents the user data received from the official HN APIs
+pub struct UserResponse {
+    id: String,
+    created: u64,
+    #[serde(default)]
+    karma: u32,
+}
+
+impl From<UserResponse> for User {
+    fn from(u: UserResponse) -> Self {
+        User {
+            username: u.id,
+            created: u.created,
+            karma: u.karma,
+        }
+    }
+}
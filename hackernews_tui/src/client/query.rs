@@ -1,7 +1,18 @@
 use crate::utils;
-use serde::Deserialize;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+/// a regex that matches a search operator with the `{key}:{value}` format
+static KEY_VALUE_OP_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(?P<key>author|site|type|after|before):(?P<value>\S+)$").unwrap());
+/// a regex that matches a search operator comparing a numeric field with a number
+static NUMERIC_OP_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?P<field>points|comments)(?P<op>>=|<=|>|<|=)(?P<value>\d+)$").unwrap()
+});
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum StorySortMode {
     None,
     Date,
@@ -38,12 +49,71 @@ impl StorySortMode {
     }
 }
 
-#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+/// `SearchTarget` defines which parts of Hacker News items a search query matches against
+pub enum SearchTarget {
+    /// stories' title and url
+    Stories,
+    /// comments' text and stories' text
+    Comments,
+}
+
+impl SearchTarget {
+    /// toggle the search target
+    pub fn toggle(self) -> Self {
+        match self {
+            Self::Stories => Self::Comments,
+            Self::Comments => Self::Stories,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
 pub struct FilterInterval<T> {
     start: Option<T>,
     end: Option<T>,
 }
 
+impl FilterInterval<u32> {
+    /// update the interval based on a comparison operator (`>`, `>=`, `<`, `<=`, `=`) and a value.
+    ///
+    /// Return `false` if the interval can't be represented, i.e. its bound overflows.
+    fn apply_op(&mut self, op: &str, x: u32) -> bool {
+        let Some(next) = x.checked_add(1) else {
+            // only `>=` and `<` don't need the value's successor
+            return match op {
+                ">=" => {
+                    self.start = Some(x);
+                    true
+                }
+                "<" => {
+                    self.end = Some(x);
+                    true
+                }
+                _ => false,
+            };
+        };
+        match op {
+            ">" => self.start = Some(next),
+            ">=" => self.start = Some(x),
+            "<" => self.end = Some(x),
+            "<=" => self.end = Some(next),
+            _ => {
+                self.start = Some(x);
+                self.end = Some(next);
+            }
+        }
+        true
+    }
+}
+
+impl<T> FilterInterval<T> {
+    pub fn is_empty(&self) -> bool {
+        self.start.is_none() && self.end.is_none()
+    }
+}
+
 impl<T: std::fmt::Display> FilterInterval<T> {
     pub fn query(&self, field: &str) -> String {
         format!(
@@ -75,7 +145,7 @@ impl<T: std::fmt::Display> FilterInterval<T> {
     }
 }
 
-#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
 /// `StoryNumericFilters` defines a list of options to filter stories
 pub struct StoryNumericFilters {
     #[serde(default)]
@@ -83,7 +153,11 @@ pub struct StoryNumericFilters {
     #[serde(default)]
     points_interval: FilterInterval<u32>,
     #[serde(default)]
-    num_comments_interval: FilterInterval<usize>,
+    num_comments_interval: FilterInterval<u32>,
+    /// an interval of the stories' creation time (in unix time),
+    /// which is only specified via search operators
+    #[serde(skip)]
+    created_at_interval: FilterInterval<u64>,
 }
 
 impl StoryNumericFilters {
@@ -117,8 +191,9 @@ impl StoryNumericFilters {
         };
 
         let mut query = format!(
-            "{}{}{}",
+            "{}{}{}{}",
             time_interval.query("created_at_i"),
+            self.created_at_interval.query("created_at_i"),
             self.points_interval.query("points"),
             self.num_comments_interval.query("num_comments")
         );
@@ -136,3 +211,280 @@ impl std::fmt::Display for StoryNumericFilters {
         write!(f, "{}", self.desc())
     }
 }
+
+/// A search query consisting of a text and search filters parsed from
+/// the query's search operators (e.g `author:pg`, `points>100`, `after:2024-01-01`)
+#[derive(Debug, Clone, Default)]
+pub struct SearchQuery {
+    /// the query's text (without the search operators)
+    pub text: String,
+    pub filters: SearchFilters,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// `SearchFilters` defines a list of options to filter search results
+pub struct SearchFilters {
+    pub author: Option<String>,
+    pub site: Option<String>,
+    /// the story type's tag, which is either `ask_hn`, `show_hn` or `poll`
+    pub story_type: Option<&'static str>,
+    pub numeric_filters: StoryNumericFilters,
+}
+
+impl SearchQuery {
+    /// parse a raw search query.
+    ///
+    /// Words that are not valid search operators are considered as the query's text.
+    pub fn parse(query: &str) -> Self {
+        let mut text = vec![];
+        let mut filters = SearchFilters::default();
+
+        for word in query.split_whitespace() {
+            if !filters.parse_op(word) {
+                text.push(word);
+            }
+        }
+
+        Self {
+            text: text.join(" "),
+            filters,
+        }
+    }
+}
+
+impl SearchFilters {
+    /// try to parse a search operator and update the filters accordingly.
+    ///
+    /// Return `false` if the word is not a valid search operator.
+    fn parse_op(&mut self, word: &str) -> bool {
+        if let Some(caps) = KEY_VALUE_OP_RE.captures(word) {
+            let value = caps.name("value").unwrap().as_str();
+            match caps.name("key").unwrap().as_str() {
+                // the author is used in the request's URL, so it must be a valid username
+                "author" if utils::is_valid_username(value) => {
+                    self.author = Some(value.to_string())
+                }
+                "site" => self.site = Some(value.to_lowercase()),
+                "type" => {
+                    self.story_type = match value {
+                        "ask" => Some("ask_hn"),
+                        "show" => Some("show_hn"),
+                        "poll" => Some("poll"),
+                        _ => return false,
+                    }
+                }
+                // `after:{date}` matches items created after the date (exclusively)
+                "after" => match utils::from_date_to_unix_time(value) {
+                    Some(time) => {
+                        self.numeric_filters.created_at_interval.start =
+                            Some(time + utils::from_day_offset_to_time_offset_in_secs(1))
+                    }
+                    None => return false,
+                },
+                // `before:{date}` matches items created before the date (exclusively)
+                "before" => match utils::from_date_to_unix_time(value) {
+                    Some(time) => self.numeric_filters.created_at_interval.end = Some(time),
+                    None => return false,
+                },
+                _ => return false,
+            }
+            true
+        } else if let Some(caps) = NUMERIC_OP_RE.captures(word) {
+            let Ok(value) = caps.name("value").unwrap().as_str().parse::<u32>() else {
+                return false;
+            };
+            let op = caps.name("op").unwrap().as_str();
+            // a word whose operator can't be applied is treated as a plain query text
+            match caps.name("field").unwrap().as_str() {
+                "points" => self.numeric_filters.points_interval.apply_op(op, value),
+                _ => self
+                    .numeric_filters
+                    .num_comments_interval
+                    .apply_op(op, value),
+            }
+        } else {
+            false
+        }
+    }
+
+    /// Get the Algolia `tags` query string of the filters for a search target.
+    ///
+    /// The story type filter is ignored when searching comments because comments
+    /// don't have the story type tags.
+    pub fn tags(&self, target: SearchTarget) -> String {
+        let mut tags = match target {
+            SearchTarget::Stories => self.story_type.unwrap_or("story"),
+            SearchTarget::Comments => "(story,comment)",
+        }
+        .to_string();
+        if let Some(author) = &self.author {
+            tags.push_str(&format!(",author_{author}"));
+        }
+        tags
+    }
+
+    /// Check if an item's URL satisfies the `site` filter
+    pub fn match_site(&self, url: &str) -> bool {
+        match &self.site {
+            None => true,
+            Some(site) => utils::get_url_domain(url)
+                .is_some_and(|domain| utils::match_domain(&domain.to_lowercase(), site)),
+        }
+    }
+
+    /// Get the description of active filters for a search target
+    pub fn desc(&self, target: SearchTarget) -> String {
+        let mut desc = vec![];
+        if let Some(author) = &self.author {
+            desc.push(format!("author: {author}"));
+        }
+        if let Some(site) = &self.site {
+            desc.push(match target {
+                SearchTarget::Stories => format!("site: {site}"),
+                // the site can't be added to the query's text of a comment search,
+                // so the site filter only applies to the matches of each result page
+                SearchTarget::Comments => format!("site: {site} (applied per page for comments)"),
+            });
+        }
+        if let Some(story_type) = self.story_type {
+            desc.push(match target {
+                SearchTarget::Stories => format!("type: {story_type}"),
+                SearchTarget::Comments => format!("type: {story_type} (ignored for comments)"),
+            });
+        }
+
+        let numeric_filters = &self.numeric_filters;
+        if !numeric_filters.points_interval.is_empty() {
+            desc.push(numeric_filters.points_interval.desc("points"));
+        }
+        if !numeric_filters.num_comments_interval.is_empty() {
+            desc.push(numeric_filters.num_comments_interval.desc("num_comments"));
+        }
+        let created_at_interval = &numeric_filters.created_at_interval;
+        if !created_at_interval.is_empty() {
+            desc.push(
+                FilterInterval {
+                    start: created_at_interval.start.map(utils::from_unix_time_to_date),
+                    end: created_at_interval.end.map(utils::from_unix_time_to_date),
+                }
+                .desc("created_at"),
+            );
+        }
+
+        desc.join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_text_without_operators() {
+        let query = SearchQuery::parse("  rust   async runtime ");
+        assert_eq!(query.text, "rust async runtime");
+        assert_eq!(query.filters, SearchFilters::default());
+    }
+
+    #[test]
+    fn parse_key_value_operators() {
+        let query = SearchQuery::parse("author:pg site:GitHub.com type:show rust");
+        assert_eq!(query.text, "rust");
+        assert_eq!(query.filters.author.as_deref(), Some("pg"));
+        assert_eq!(query.filters.site.as_deref(), Some("github.com"));
+        assert_eq!(query.filters.story_type, Some("show_hn"));
+    }
+
+    #[test]
+    fn parse_invalid_operators_as_text() {
+        let query = SearchQuery::parse("author:a&b type:job after:2024-02-31 before:yesterday");
+        assert_eq!(
+            query.text,
+            "author:a&b type:job after:2024-02-31 before:yesterday"
+        );
+        assert_eq!(query.filters, SearchFilters::default());
+    }
+
+    #[test]
+    fn parse_date_operators() {
+        let query = SearchQuery::parse("after:2024-01-01 before:2024-03-01");
+        let interval = query.filters.numeric_filters.created_at_interval;
+        // `after` excludes the given date
+        assert_eq!(interval.start, Some(1704153600));
+        assert_eq!(interval.end, Some(1709251200));
+    }
+
+    #[test]
+    fn parse_numeric_operators() {
+        let query = SearchQuery::parse("points>100 comments<=5");
+        let filters = query.filters.numeric_filters;
+        assert_eq!(query.text, "");
+        assert_eq!(
+            filters.points_interval,
+            FilterInterval {
+                start: Some(101),
+                end: None
+            }
+        );
+        assert_eq!(
+            filters.num_comments_interval,
+            FilterInterval {
+                start: None,
+                end: Some(6)
+            }
+        );
+    }
+
+    #[test]
+    fn parse_out_of_range_numeric_operators_as_text() {
+        let query = SearchQuery::parse("points>4294967296");
+        assert_eq!(query.text, "points>4294967296");
+        assert!(query.filters.numeric_filters.points_interval.is_empty());
+    }
+
+    #[test]
+    fn apply_op_without_overflow() {
+        let mut interval = FilterInterval::default();
+        assert!(interval.apply_op("=", 10));
+        assert_eq!(
+            interval,
+            FilterInterval {
+                start: Some(10),
+                end: Some(11)
+            }
+        );
+    }
+
+    #[test]
+    fn apply_op_with_overflow() {
+        for op in [">", "<=", "="] {
+            let mut interval = FilterInterval::default();
+            assert!(!interval.apply_op(op, u32::MAX), "`{op}` should overflow");
+            assert!(interval.is_empty());
+        }
+
+        let mut interval = FilterInterval::default();
+        assert!(interval.apply_op(">=", u32::MAX));
+        assert!(interval.apply_op("<", u32::MAX));
+        assert_eq!(
+            interval,
+            FilterInterval {
+                start: Some(u32::MAX),
+                end: Some(u32::MAX)
+            }
+        );
+    }
+
+    #[test]
+    fn parse_overflowed_operator_as_text() {
+        let query = SearchQuery::parse("points>4294967295 points>=4294967295");
+        assert_eq!(query.text, "points>4294967295");
+        assert_eq!(
+            query.filters.numeric_filters.points_interval,
+            FilterInterval {
+                start: Some(u32::MAX),
+                end: None
+            }
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use config_parser2::*;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Deserialize;
+
+/// compiled regexes of the `title_regexes` filtering rules
+static TITLE_REGEXES: Lazy<Vec<Regex>> = Lazy::new(|| {
+    super::get_config()
+        .filter
+        .title_regexes
+        .iter()
+        .filter_map(|re| match Regex::new(re) {
+            Ok(re) => Some(re),
+            Err(err) => {
+                tracing::warn!("failed to parse the title regex {re}: {err}");
+                None
+            }
+        })
+        .collect()
+});
+
+#[derive(Default, Debug, Clone, Deserialize, ConfigParse)]
+/// Filter is a set of rules to filter out stories, search results and comments
+pub struct Filter {
+    /// regexes matching titles of filtered stories
+    pub title_regexes: Vec<String>,
+    /// domains (including their subdomains) of filtered stories
+    pub domains: Vec<String>,
+    /// users whose stories and comments are filtered
+    pub users: Vec<String>,
+    /// whether to show filtered items dimmed instead of hiding them
+    pub dim_filtered_items: bool,
+}
+
+impl Filter {
+    /// Check if a story's title matches the filtering rules
+    pub fn match_title(&self, title: &str) -> bool {
+        TITLE_REGEXES.iter().any(|re| re.is_match(title))
+    }
+
+    /// Check if a story's URL matches the filtering rules
+    pub fn match_url(&self, url: &str) -> bool {
+        match crate::utils::get_url_domain(url) {
+            Some(domain) => self
+                .domains
+                .iter()
+                .any(|d| crate::utils::match_domain(&domain, d)),
+            None => false,
+        }
+    }
+
+    /// Check if a user matches the filtering rules
+    pub fn match_user(&self, user: &str) -> bool {
+        self.users.iter().any(|u| u == user)
+    }
+}
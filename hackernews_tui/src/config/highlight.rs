@@ -0,0 +1,102 @@
+use super::Style;
+use cursive::utils::markup::StyledString;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Deserialize;
+
+/// compiled regexes of the highlight rules' keywords
+static KEYWORD_RULES: Lazy<Vec<(Regex, Style)>> = Lazy::new(|| {
+    super::get_config()
+        .highlight_rules
+        .iter()
+        .filter_map(|rule| {
+            let keyword = rule.keyword.as_ref()?;
+            match Regex::new(keyword) {
+                Ok(re) => Some((re, rule.style)),
+                Err(err) => {
+                    tracing::warn!("failed to parse the highlight keyword {keyword}: {err}");
+                    None
+                }
+            }
+        })
+        .collect()
+});
+
+#[derive(Debug, Clone, Deserialize)]
+/// HighlightRule defines a style applied to story titles and comments matching certain conditions
+pub struct HighlightRule {
+    /// a regex matching keywords in story titles and comments
+    pub keyword: Option<String>,
+    /// a domain (including its subdomains) of highlighted stories
+    pub domain: Option<String>,
+    /// a user whose stories and comments are highlighted
+    pub user: Option<String>,
+    pub style: Style,
+}
+
+/// Apply the style of highlight rules to parts of a text matching the rules' keywords
+pub fn highlight_keywords(text: StyledString) -> StyledString {
+    if KEYWORD_RULES.is_empty() {
+        return text;
+    }
+
+    let mut highlighted_text = StyledString::new();
+    for span in text.spans() {
+        // the style of each byte in the span's content, which is
+        // the span's style combined with the styles of matched keywords
+        let mut styles = vec![*span.attr; span.content.len()];
+        for (re, style) in KEYWORD_RULES.iter() {
+            for m in re.find_iter(span.content) {
+                styles[m.range()]
+                    .iter_mut()
+                    .for_each(|s| *s = s.combine(cursive::theme::Style::from(*style)));
+            }
+        }
+
+        // split the span's content into parts having the same style
+        let mut start = 0;
+        for end in 1..=span.content.len() {
+            if end == span.content.len() || styles[end] != styles[start] {
+                highlighted_text.append_styled(&span.content[start..end], styles[start]);
+                start = end;
+            }
+        }
+    }
+    highlighted_text
+}
+
+/// Get the combined style of highlight rules matching a story's URL or author
+pub fn get_story_highlight_style(url: &str, author: &str) -> Option<cursive::theme::Style> {
+    let domain = crate::utils::get_url_domain(url);
+    super::get_config()
+        .highlight_rules
+        .iter()
+        .filter(|rule| {
+            rule.user.as_deref() == Some(author)
+                || match (&rule.domain, &domain) {
+                    (Some(d), Some(domain)) => crate::utils::match_domain(domain, d),
+                    _ => false,
+                }
+        })
+        .map(|rule| cursive::theme::Style::from(rule.style))
+        .reduce(|acc, style| acc.combine(style))
+}
+
+/// Get the combined style of highlight rules matching a user
+pub fn get_user_highlight_style(user: &str) -> Option<cursive::theme::Style> {
+    super::get_config()
+        .highlight_rules
+        .iter()
+        .filter(|rule| rule.user.as_deref() == Some(user))
+        .map(|rule| cursive::theme::Style::from(rule.style))
+        .reduce(|acc, style| acc.combine(style))
+}
+
+/// Apply a style to all parts of a text
+pub fn apply_style(text: StyledString, style: cursive::theme::Style) -> StyledString {
+    let mut styled_text = StyledString::new();
+    for span in text.spans() {
+        styled_text.append_styled(span.content, span.attr.combine(style));
+    }
+    styled_text
+}
@@ -7,6 +7,9 @@ use serde::{de, Deserialize, Deserializer};
 pub struct KeyMap {
     pub edit_keymap: EditKeyMap,
     pub scroll_keymap: ScrollKeyMap,
+    pub find_keymap: FindKeyMap,
+    pub split_view_keymap: SplitViewKeyMap,
+    pub command_palette_keymap: CommandPaletteKeyMap,
     pub global_keymap: GlobalKeyMap,
     pub story_view_keymap: StoryViewKeyMap,
     pub search_view_keymap: SearchViewKeyMap,
@@ -20,13 +23,183 @@ pub struct KeyMap {
 #[derive(Debug, Clone, Deserialize)]
 pub struct CustomKeyMap {
     pub key: Keys,
-    pub tag: String,
-    pub by_date: bool,
-    pub numeric_filters: client::StoryNumericFilters,
+    #[serde(flatten)]
+    pub view: CustomView,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+/// A view that a custom keymap navigates to
+pub enum CustomView {
+    /// a story view with stories filtered by certain conditions
+    StoryView {
+        tag: String,
+        by_date: bool,
+        numeric_filters: client::StoryNumericFilters,
+    },
+    /// a search view of a saved search
+    SavedSearch { saved_search: String },
 }
 
 config_parser_impl!(CustomKeyMap);
 
+impl KeyMap {
+    /// gets the keys of each keymap along with their full command names
+    fn get_named_keys(&self) -> Vec<(&'static str, Vec<(String, &Keys)>)> {
+        let keymaps = [
+            ("edit_keymap", self.edit_keymap.named_keys()),
+            ("scroll_keymap", self.scroll_keymap.named_keys()),
+            ("find_keymap", self.find_keymap.named_keys()),
+            ("split_view_keymap", self.split_view_keymap.named_keys()),
+            (
+                "command_palette_keymap",
+                self.command_palette_keymap.named_keys(),
+            ),
+            ("global_keymap", self.global_keymap.named_keys()),
+            ("story_view_keymap", self.story_view_keymap.named_keys()),
+            ("search_view_keymap", self.search_view_keymap.named_keys()),
+            ("comment_view_keymap", self.comment_view_keymap.named_keys()),
+            ("article_view_keymap", self.article_view_keymap.named_keys()),
+            ("link_dialog_keymap", self.link_dialog_keymap.named_keys()),
+        ];
+
+        let mut named_keys = keymaps
+            .into_iter()
+            .map(|(keymap, keys)| {
+                let keys = keys
+                    .into_iter()
+                    .map(|(name, keys)| (format!("{keymap}.{name}"), keys))
+                    .collect();
+                (keymap, keys)
+            })
+            .collect::<Vec<_>>();
+        named_keys.push((
+            "custom_keymaps",
+            self.custom_keymaps
+                .iter()
+                .enumerate()
+                .map(|(i, k)| (format!("custom_keymaps[{i}]"), &k.key))
+                .collect(),
+        ));
+        named_keys
+    }
+
+    /// gets all multi-key sequences defined in the keymap
+    pub fn get_key_sequences(&self) -> Vec<&[event::Event]> {
+        self.get_named_keys()
+            .into_iter()
+            .flat_map(|(_, keys)| keys)
+            .flat_map(|(_, keys)| keys.sequences())
+            .filter(|s| s.len() > 1)
+            .map(|s| s.as_slice())
+            .collect()
+    }
+
+    /// validates the keymap, returning the problems found in the keymap, which include
+    /// - key strings failed to be parsed
+    /// - keys bound to multiple commands in the same view scope
+    pub fn validate(&self) -> Vec<String> {
+        let named_keys = self.get_named_keys();
+        let get_keymap_keys = |keymap: &str| {
+            named_keys
+                .iter()
+                .find(|(k, _)| *k == keymap)
+                .map(|(_, keys)| keys.as_slice())
+                .unwrap_or_default()
+        };
+
+        let mut problems = named_keys
+            .iter()
+            .flat_map(|(_, keys)| keys)
+            .flat_map(|(name, keys)| {
+                keys.errors()
+                    .iter()
+                    .map(move |err| format!("`{name}`: {err}"))
+            })
+            .collect::<Vec<_>>();
+
+        // keys handled in every view. `close_dialog` is excluded as it's only handled by dialogs.
+        let global_keys = get_keymap_keys("global_keymap")
+            .iter()
+            .filter(|(name, _)| name != "global_keymap.close_dialog")
+            .chain(get_keymap_keys("custom_keymaps"))
+            .collect::<Vec<_>>();
+
+        // view-specific keymaps, each of which shares the same scope with the global keys
+        for keymap in [
+            "story_view_keymap",
+            "search_view_keymap",
+            "comment_view_keymap",
+            "article_view_keymap",
+            "link_dialog_keymap",
+        ] {
+            let scope = get_keymap_keys(keymap)
+                .iter()
+                .chain(global_keys.iter().copied())
+                .collect::<Vec<_>>();
+            find_key_conflicts(&scope, &mut problems);
+        }
+
+        // keymaps shared by multiple views, whose keys take precedence over views' keys
+        for keymap in [
+            "edit_keymap",
+            "scroll_keymap",
+            "find_keymap",
+            "split_view_keymap",
+            "command_palette_keymap",
+        ] {
+            let scope = get_keymap_keys(keymap).iter().collect::<Vec<_>>();
+            find_key_conflicts(&scope, &mut problems);
+        }
+
+        problems
+    }
+}
+
+/// Find keys bound to multiple commands in the same scope.
+///
+/// A key sequence conflicts with another if they are equal or one is a prefix of the other.
+fn find_key_conflicts(scope: &[&(String, &Keys)], conflicts: &mut Vec<String>) {
+    for (i, (name, keys)) in scope.iter().enumerate() {
+        for (other_name, other_keys) in &scope[i + 1..] {
+            for s in keys.sequences() {
+                for o in other_keys.sequences() {
+                    let conflict = if s == o {
+                        format!(
+                            "`{}` is bound to both `{name}` and `{other_name}`",
+                            SequenceDesc(s)
+                        )
+                    } else if s.starts_with(o) || o.starts_with(s) {
+                        format!(
+                            "`{}` of `{name}` and `{}` of `{other_name}` conflict, one is a prefix of the other",
+                            SequenceDesc(s),
+                            SequenceDesc(o)
+                        )
+                    } else {
+                        continue;
+                    };
+
+                    if !conflicts.contains(&conflict) {
+                        conflicts.push(conflict);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Implement a `named_keys` function returning a keymap's keys along with their command names
+macro_rules! impl_named_keys {
+    ($keymap:ident { $($field:ident),* $(,)? }) => {
+        impl $keymap {
+            pub fn named_keys(&self) -> Vec<(&'static str, &Keys)> {
+                let Self { $($field),* } = self;
+                vec![$((stringify!($field), $field)),*]
+            }
+        }
+    };
+}
+
 #[derive(Debug, Clone, Deserialize, ConfigParse)]
 pub struct EditKeyMap {
     pub move_cursor_left: Keys,
@@ -34,6 +207,8 @@ pub struct EditKeyMap {
     pub move_cursor_to_begin: Keys,
     pub move_cursor_to_end: Keys,
     pub backward_delete_char: Keys,
+    pub prev_history_entry: Keys,
+    pub next_history_entry: Keys,
 }
 
 impl Default for EditKeyMap {
@@ -53,10 +228,22 @@ impl Default for EditKeyMap {
                 event::Event::CtrlChar('e'),
             ]),
             backward_delete_char: Keys::new(vec![event::Key::Backspace.into()]),
+            prev_history_entry: Keys::new(vec![event::Key::Up.into()]),
+            next_history_entry: Keys::new(vec![event::Key::Down.into()]),
         }
     }
 }
 
+impl_named_keys!(EditKeyMap {
+    move_cursor_left,
+    move_cursor_right,
+    move_cursor_to_begin,
+    move_cursor_to_end,
+    backward_delete_char,
+    prev_history_entry,
+    next_history_entry,
+});
+
 #[derive(Debug, Clone, Deserialize, ConfigParse)]
 pub struct ScrollKeyMap {
     pub up: Keys,
@@ -80,26 +267,117 @@ impl Default for ScrollKeyMap {
     }
 }
 
+impl_named_keys!(ScrollKeyMap {
+    up,
+    down,
+    page_up,
+    page_down,
+    top,
+    bottom,
+});
+
+#[derive(Debug, Clone, Deserialize, ConfigParse)]
+pub struct FindKeyMap {
+    pub open_find_bar: Keys,
+    pub close_find_bar: Keys,
+    pub confirm_find_query: Keys,
+    pub next_match: Keys,
+    pub prev_match: Keys,
+}
+
+impl Default for FindKeyMap {
+    fn default() -> Self {
+        FindKeyMap {
+            open_find_bar: Keys::new(vec![event::Event::CtrlChar('f')]),
+            close_find_bar: Keys::new(vec![event::Key::Esc.into()]),
+            confirm_find_query: Keys::new(vec![event::Key::Enter.into()]),
+            next_match: Keys::new(vec!['>'.into()]),
+            prev_match: Keys::new(vec!['<'.into()]),
+        }
+    }
+}
+
+impl_named_keys!(FindKeyMap {
+    open_find_bar,
+    close_find_bar,
+    confirm_find_query,
+    next_match,
+    prev_match,
+});
+
+#[derive(Debug, Clone, Deserialize, ConfigParse)]
+pub struct SplitViewKeyMap {
+    pub toggle_pane_focus: Keys,
+    pub toggle_preview_mode: Keys,
+}
+
+impl Default for SplitViewKeyMap {
+    fn default() -> Self {
+        SplitViewKeyMap {
+            toggle_pane_focus: Keys::new(vec![event::Event::CtrlChar('w')]),
+            toggle_preview_mode: Keys::new(vec!['P'.into()]),
+        }
+    }
+}
+
+impl_named_keys!(SplitViewKeyMap {
+    toggle_pane_focus,
+    toggle_preview_mode,
+});
+
+#[derive(Debug, Clone, Deserialize, ConfigParse)]
+pub struct CommandPaletteKeyMap {
+    pub next_command: Keys,
+    pub prev_command: Keys,
+    pub run_command: Keys,
+}
+
+impl Default for CommandPaletteKeyMap {
+    fn default() -> Self {
+        CommandPaletteKeyMap {
+            next_command: Keys::new(vec![event::Key::Down.into()]),
+            prev_command: Keys::new(vec![event::Key::Up.into()]),
+            run_command: Keys::new(vec![event::Key::Enter.into()]),
+        }
+    }
+}
+
+impl_named_keys!(CommandPaletteKeyMap {
+    next_command,
+    prev_command,
+    run_command,
+});
+
 #[derive(Debug, Clone, Deserialize, ConfigParse)]
 pub struct GlobalKeyMap {
     pub open_help_dialog: Keys,
+    pub open_command_palette: Keys,
     pub quit: Keys,
     pub close_dialog: Keys,
 
     // view navigation keymaps
     pub goto_previous_view: Keys,
+    pub goto_next_view: Keys,
+    pub goto_history_view: Keys,
     pub goto_front_page_view: Keys,
     pub goto_search_view: Keys,
     pub goto_all_stories_view: Keys,
     pub goto_ask_hn_view: Keys,
     pub goto_show_hn_view: Keys,
     pub goto_jobs_view: Keys,
+
+    // tab keymaps
+    pub new_tab: Keys,
+    pub close_tab: Keys,
+    pub next_tab: Keys,
+    pub prev_tab: Keys,
 }
 
 impl Default for GlobalKeyMap {
     fn default() -> Self {
         GlobalKeyMap {
             open_help_dialog: Keys::new(vec!['?'.into()]),
+            open_command_palette: Keys::new(vec![':'.into()]),
             quit: Keys::new(vec!['q'.into(), event::Event::CtrlChar('c')]),
             close_dialog: Keys::new(vec![event::Key::Esc.into()]),
 
@@ -107,6 +385,8 @@ impl Default for GlobalKeyMap {
                 event::Key::Backspace.into(),
                 event::Event::CtrlChar('p'),
             ]),
+            goto_next_view: Keys::new(vec![event::Event::CtrlChar('n')]),
+            goto_history_view: Keys::new(vec!['H'.into()]),
 
             goto_search_view: Keys::new(vec!['/'.into(), event::Event::CtrlChar('s')]),
 
@@ -115,10 +395,35 @@ impl Default for GlobalKeyMap {
             goto_ask_hn_view: Keys::new(vec![event::Key::F3.into()]),
             goto_show_hn_view: Keys::new(vec![event::Key::F4.into()]),
             goto_jobs_view: Keys::new(vec![event::Key::F5.into()]),
+
+            new_tab: Keys::new(vec![event::Event::AltChar('t')]),
+            close_tab: Keys::new(vec![event::Event::AltChar('w')]),
+            next_tab: Keys::new(vec![event::Event::AltChar('l')]),
+            prev_tab: Keys::new(vec![event::Event::AltChar('h')]),
         }
     }
 }
 
+impl_named_keys!(GlobalKeyMap {
+    open_help_dialog,
+    open_command_palette,
+    quit,
+    close_dialog,
+    goto_previous_view,
+    goto_next_view,
+    goto_history_view,
+    goto_front_page_view,
+    goto_search_view,
+    goto_all_stories_view,
+    goto_ask_hn_view,
+    goto_show_hn_view,
+    goto_jobs_view,
+    new_tab,
+    close_tab,
+    next_tab,
+    prev_tab,
+});
+
 #[derive(Debug, Clone, Deserialize, ConfigParse)]
 pub struct StoryViewKeyMap {
     // story tags navigation keymaps
@@ -141,6 +446,10 @@ pub struct StoryViewKeyMap {
     pub open_story_in_browser: Keys,
 
     pub goto_story_comment_view: Keys,
+
+    // tab keymaps
+    pub open_story_in_new_tab: Keys,
+    pub open_article_in_new_tab: Keys,
 }
 
 impl Default for StoryViewKeyMap {
@@ -161,15 +470,38 @@ impl Default for StoryViewKeyMap {
             open_story_in_browser: Keys::new(vec!['s'.into()]),
 
             goto_story_comment_view: Keys::new(vec![event::Key::Enter.into()]),
+
+            open_story_in_new_tab: Keys::new(vec!['T'.into()]),
+            open_article_in_new_tab: Keys::new(vec![event::Event::AltChar('o')]),
         }
     }
 }
 
+impl_named_keys!(StoryViewKeyMap {
+    next_story_tag,
+    prev_story_tag,
+    next_story,
+    prev_story,
+    goto_story,
+    next_page,
+    prev_page,
+    cycle_sort_mode,
+    open_article_in_browser,
+    open_article_in_article_view,
+    open_story_in_browser,
+    goto_story_comment_view,
+    open_story_in_new_tab,
+    open_article_in_new_tab,
+});
+
 #[derive(Debug, Clone, Deserialize, ConfigParse)]
 pub struct SearchViewKeyMap {
     // switch mode keymaps
     pub to_navigation_mode: Keys,
     pub to_search_mode: Keys,
+
+    pub toggle_search_target: Keys,
+    pub save_search: Keys,
 }
 
 impl Default for SearchViewKeyMap {
@@ -177,10 +509,20 @@ impl Default for SearchViewKeyMap {
         SearchViewKeyMap {
             to_navigation_mode: Keys::new(vec![event::Key::Esc.into()]),
             to_search_mode: Keys::new(vec!['i'.into()]),
+
+            toggle_search_target: Keys::new(vec![event::Event::CtrlChar('t')]),
+            save_search: Keys::new(vec![event::Event::AltChar('s')]),
         }
     }
 }
 
+impl_named_keys!(SearchViewKeyMap {
+    to_navigation_mode,
+    to_search_mode,
+    toggle_search_target,
+    save_search,
+});
+
 #[derive(Debug, Clone, Deserialize, ConfigParse)]
 pub struct CommentViewKeyMap {
     // comments navigation keymaps
@@ -191,6 +533,8 @@ pub struct CommentViewKeyMap {
     pub next_leq_level_comment: Keys,
     pub prev_leq_level_comment: Keys,
     pub parent_comment: Keys,
+    pub next_op_comment: Keys,
+    pub prev_op_comment: Keys,
 
     // link keymaps
     pub open_story_in_browser: Keys,
@@ -203,6 +547,14 @@ pub struct CommentViewKeyMap {
     pub vote: Keys,
 
     pub toggle_collapse_comment: Keys,
+    pub collapse_all_comments: Keys,
+    pub expand_all_comments: Keys,
+    pub collapse_comments_to_depth: Keys,
+    pub refresh: Keys,
+    pub cycle_sort_mode: Keys,
+    pub filter_comments: Keys,
+    pub toggle_user_metadata: Keys,
+    pub edit_user_note: Keys,
 }
 
 impl Default for CommentViewKeyMap {
@@ -215,6 +567,8 @@ impl Default for CommentViewKeyMap {
             next_leq_level_comment: Keys::new(vec!['l'.into()]),
             prev_leq_level_comment: Keys::new(vec!['h'.into()]),
             parent_comment: Keys::new(vec!['u'.into()]),
+            next_op_comment: Keys::new(vec![']'.into()]),
+            prev_op_comment: Keys::new(vec!['['.into()]),
 
             open_comment_in_browser: Keys::new(vec!['c'.into()]),
             open_story_in_browser: Keys::new(vec!['s'.into()]),
@@ -226,10 +580,46 @@ impl Default for CommentViewKeyMap {
             vote: Keys::new(vec!['v'.into()]),
 
             toggle_collapse_comment: Keys::new(vec![event::Key::Tab.into()]),
+            collapse_all_comments: Keys::new(vec!['C'.into()]),
+            expand_all_comments: Keys::new(vec!['E'.into()]),
+            collapse_comments_to_depth: Keys::new(vec!['D'.into()]),
+            refresh: Keys::new(vec!['r'.into()]),
+            cycle_sort_mode: Keys::new(vec!['S'.into()]),
+            filter_comments: Keys::new(vec!['f'.into()]),
+            toggle_user_metadata: Keys::new(vec!['U'.into()]),
+            edit_user_note: Keys::new(vec!['N'.into()]),
         }
     }
 }
 
+impl_named_keys!(CommentViewKeyMap {
+    next_comment,
+    prev_comment,
+    next_top_level_comment,
+    prev_top_level_comment,
+    next_leq_level_comment,
+    prev_leq_level_comment,
+    parent_comment,
+    next_op_comment,
+    prev_op_comment,
+    open_story_in_browser,
+    open_comment_in_browser,
+    open_article_in_browser,
+    open_article_in_article_view,
+    open_link_in_browser,
+    open_link_in_article_view,
+    vote,
+    toggle_collapse_comment,
+    collapse_all_comments,
+    expand_all_comments,
+    collapse_comments_to_depth,
+    refresh,
+    cycle_sort_mode,
+    filter_comments,
+    toggle_user_metadata,
+    edit_user_note,
+});
+
 #[derive(Debug, Clone, Deserialize, ConfigParse)]
 pub struct ArticleViewKeyMap {
     pub open_link_dialog: Keys,
@@ -249,6 +639,13 @@ impl Default for ArticleViewKeyMap {
     }
 }
 
+impl_named_keys!(ArticleViewKeyMap {
+    open_link_dialog,
+    open_article_in_browser,
+    open_link_in_browser,
+    open_link_in_article_view,
+});
+
 #[derive(Debug, Clone, Deserialize, ConfigParse)]
 pub struct LinkDialogKeyMap {
     pub next: Keys,
@@ -268,9 +665,19 @@ impl Default for LinkDialogKeyMap {
     }
 }
 
+impl_named_keys!(LinkDialogKeyMap {
+    next,
+    prev,
+    open_link_in_browser,
+    open_link_in_article_view,
+});
+
 #[derive(Debug, Clone)]
 pub struct Keys {
-    events: Vec<event::Event>,
+    /// key sequences triggering a command, each of which consists of one or more events
+    sequences: Vec<Vec<event::Event>>,
+    /// errors from parsing the key strings of the keys
+    errors: Vec<String>,
 }
 
 impl From<Keys> for event::EventTrigger {
@@ -279,62 +686,99 @@ impl From<Keys> for event::EventTrigger {
     }
 }
 
-impl std::fmt::Display for Keys {
+fn fmt_event(e: &event::Event, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match e {
+        event::Event::Char(' ') => write!(f, "space"),
+        event::Event::Char(c) => write!(f, "{c}"),
+        event::Event::CtrlChar(c) => write!(f, "C-{c}"),
+        event::Event::AltChar(c) => write!(f, "M-{c}"),
+        event::Event::Key(k) => match k {
+            event::Key::Enter => write!(f, "enter"),
+            event::Key::Tab => write!(f, "tab"),
+            event::Key::Backspace => write!(f, "backspace"),
+            event::Key::Esc => write!(f, "esc"),
+
+            event::Key::Left => write!(f, "left"),
+            event::Key::Right => write!(f, "right"),
+            event::Key::Up => write!(f, "up"),
+            event::Key::Down => write!(f, "down"),
+
+            event::Key::Ins => write!(f, "ins"),
+            event::Key::Del => write!(f, "del"),
+            event::Key::Home => write!(f, "home"),
+            event::Key::End => write!(f, "end"),
+            event::Key::PageUp => write!(f, "page_up"),
+            event::Key::PageDown => write!(f, "page_down"),
+
+            event::Key::F1 => write!(f, "f1"),
+            event::Key::F2 => write!(f, "f2"),
+            event::Key::F3 => write!(f, "f3"),
+            event::Key::F4 => write!(f, "f4"),
+            event::Key::F5 => write!(f, "f5"),
+            event::Key::F6 => write!(f, "f6"),
+            event::Key::F7 => write!(f, "f7"),
+            event::Key::F8 => write!(f, "f8"),
+            event::Key::F9 => write!(f, "f9"),
+            event::Key::F10 => write!(f, "f10"),
+            event::Key::F11 => write!(f, "f11"),
+            event::Key::F12 => write!(f, "f12"),
+
+            _ => panic!("unknown key: {k:?}"),
+        },
+        _ => panic!("unknown event: {e:?}"),
+    }
+}
+
+/// A wrapper to display a key sequence, in which
+/// a single character is displayed as is and other keys are wrapped in `<>`, e.g. `<space>c`.
+pub struct KeySequence<'a>(pub &'a [event::Event]);
+
+impl std::fmt::Display for KeySequence<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        fn fmt_event(e: &event::Event, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for e in self.0 {
             match e {
-                event::Event::Char(c) => write!(f, "{c}"),
-                event::Event::CtrlChar(c) => write!(f, "C-{c}"),
-                event::Event::AltChar(c) => write!(f, "M-{c}"),
-                event::Event::Key(k) => match k {
-                    event::Key::Enter => write!(f, "enter"),
-                    event::Key::Tab => write!(f, "tab"),
-                    event::Key::Backspace => write!(f, "backspace"),
-                    event::Key::Esc => write!(f, "esc"),
-
-                    event::Key::Left => write!(f, "left"),
-                    event::Key::Right => write!(f, "right"),
-                    event::Key::Up => write!(f, "up"),
-                    event::Key::Down => write!(f, "down"),
-
-                    event::Key::Ins => write!(f, "ins"),
-                    event::Key::Del => write!(f, "del"),
-                    event::Key::Home => write!(f, "home"),
-                    event::Key::End => write!(f, "end"),
-                    event::Key::PageUp => write!(f, "page_up"),
-                    event::Key::PageDown => write!(f, "page_down"),
-
-                    event::Key::F1 => write!(f, "f1"),
-                    event::Key::F2 => write!(f, "f2"),
-                    event::Key::F3 => write!(f, "f3"),
-                    event::Key::F4 => write!(f, "f4"),
-                    event::Key::F5 => write!(f, "f5"),
-                    event::Key::F6 => write!(f, "f6"),
-                    event::Key::F7 => write!(f, "f7"),
-                    event::Key::F8 => write!(f, "f8"),
-                    event::Key::F9 => write!(f, "f9"),
-                    event::Key::F10 => write!(f, "f10"),
-                    event::Key::F11 => write!(f, "f11"),
-                    event::Key::F12 => write!(f, "f12"),
-
-                    _ => panic!("unknown key: {k:?}"),
-                },
-                _ => panic!("unknown event: {e:?}"),
+                event::Event::Char(c) if *c != ' ' => write!(f, "{c}")?,
+                _ => {
+                    write!(f, "<")?;
+                    fmt_event(e, f)?;
+                    write!(f, ">")?;
+                }
             }
         }
+        Ok(())
+    }
+}
+
+fn fmt_sequence(s: &[event::Event], f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match s {
+        [e] => fmt_event(e, f),
+        _ => write!(f, "{}", KeySequence(s)),
+    }
+}
+
+/// A wrapper to display a key sequence the same as it's displayed in `Keys`
+struct SequenceDesc<'a>(&'a [event::Event]);
+
+impl std::fmt::Display for SequenceDesc<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_sequence(self.0, f)
+    }
+}
 
-        if self.events.is_empty() {
+impl std::fmt::Display for Keys {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.sequences.is_empty() {
             return Ok(());
         }
 
-        if self.events.len() == 1 {
-            fmt_event(&self.events[0], f)
+        if self.sequences.len() == 1 {
+            fmt_sequence(&self.sequences[0], f)
         } else {
             write!(f, "[")?;
-            fmt_event(&self.events[0], f)?;
-            for e in &self.events[1..] {
+            fmt_sequence(&self.sequences[0], f)?;
+            for s in &self.sequences[1..] {
                 write!(f, ", ")?;
-                fmt_event(e, f)?;
+                fmt_sequence(s, f)?;
             }
             write!(f, "]")?;
             Ok(())
@@ -344,15 +788,183 @@ impl std::fmt::Display for Keys {
 
 impl Keys {
     pub fn new(events: Vec<event::Event>) -> Self {
-        Keys { events }
+        Keys {
+            sequences: events.into_iter().map(|e| vec![e]).collect(),
+            errors: vec![],
+        }
     }
 
+    /// checks if an event triggers the keys' command.
+    ///
+    /// A multi-key sequence is triggered by the event returned by `get_sequence_event`.
     pub fn has_event(&self, e: &event::Event) -> bool {
-        self.events.iter().any(|x| *x == *e)
+        self.sequences.iter().any(|s| match s.as_slice() {
+            [single] => single == e,
+            _ => get_sequence_event(s) == *e,
+        })
+    }
+
+    /// gets the event triggering the keys' first key sequence
+    pub fn first_event(&self) -> Option<event::Event> {
+        self.sequences.first().map(|s| match s.as_slice() {
+            [single] => single.clone(),
+            _ => get_sequence_event(s),
+        })
+    }
+
+    pub fn sequences(&self) -> &[Vec<event::Event>] {
+        &self.sequences
+    }
+
+    /// gets errors from parsing the keys' key strings.
+    ///
+    /// Key strings failed to be parsed are ignored when loading the keys.
+    pub fn errors(&self) -> &[String] {
+        &self.errors
+    }
+}
+
+/// Get the event sent to views when a multi-key sequence is typed.
+///
+/// The event is an `Event::Unknown` event, which is never sent by the terminal backend with the same content.
+pub fn get_sequence_event(sequence: &[event::Event]) -> event::Event {
+    event::Event::Unknown(format!("key_sequence:{}", KeySequence(sequence)).into_bytes())
+}
+
+/// Convert a key string into `cursive::event::Event`
+pub fn from_key_string_to_event(ks: &str) -> Result<event::Event> {
+    let chars: Vec<char> = ks.chars().collect();
+
+    let event = if chars.len() == 1 {
+        // a single character
+        event::Event::Char(chars[0])
+    } else if chars.len() == 3 && chars[1] == '-' {
+        // M-<c> for alt-<c> and C-<c> for ctrl-<c>, with <c> denotes a single character
+        match chars[0] {
+            'C' => event::Event::CtrlChar(chars[2]),
+            'M' => event::Event::AltChar(chars[2]),
+            _ => {
+                return Err(anyhow::anyhow!(
+                    "failed to parse key: unknown/invalid key {}",
+                    ks
+                ))
+            }
+        }
+    } else if ks == "space" {
+        event::Event::Char(' ')
+    } else {
+        let key = match ks {
+            "enter" => event::Key::Enter,
+            "tab" => event::Key::Tab,
+            "backspace" => event::Key::Backspace,
+            "esc" => event::Key::Esc,
+
+            "left" => event::Key::Left,
+            "right" => event::Key::Right,
+            "up" => event::Key::Up,
+            "down" => event::Key::Down,
+
+            "ins" => event::Key::Ins,
+            "del" => event::Key::Del,
+            "home" => event::Key::Home,
+            "end" => event::Key::End,
+            "page_up" => event::Key::PageUp,
+            "page_down" => event::Key::PageDown,
+
+            "f1" => event::Key::F1,
+            "f2" => event::Key::F2,
+            "f3" => event::Key::F3,
+            "f4" => event::Key::F4,
+            "f5" => event::Key::F5,
+            "f6" => event::Key::F6,
+            "f7" => event::Key::F7,
+            "f8" => event::Key::F8,
+            "f9" => event::Key::F9,
+            "f10" => event::Key::F10,
+            "f11" => event::Key::F11,
+            "f12" => event::Key::F12,
+
+            _ => {
+                return Err(anyhow::anyhow!(
+                    "failed to parse key: unknown/invalid key {}",
+                    ks
+                ))
+            }
+        };
+
+        event::Event::Key(key)
+    };
+
+    Ok(event)
+}
+
+/// Convert a key string into a key sequence.
+///
+/// A key string is either a single key (e.g. `g`, `C-x` or `enter`) or a sequence of keys,
+/// in which a single character represents itself and other keys are wrapped in `<>` (e.g. `gg` or `<space>c`).
+pub fn from_key_string_to_sequence(ks: &str) -> Result<Vec<event::Event>> {
+    if let Ok(e) = from_key_string_to_event(ks) {
+        return Ok(vec![e]);
     }
+
+    let mut sequence = vec![];
+    // the number of consecutive letters not wrapped in `<>`
+    let mut n_letters = 0;
+    let mut chars = ks.chars();
+    while let Some(c) = chars.next() {
+        if c == '<' {
+            n_letters = 0;
+            let mut key = String::new();
+            loop {
+                match chars.next() {
+                    Some('>') => break,
+                    Some(c) => key.push(c),
+                    None => {
+                        return Err(anyhow::anyhow!(
+                            "failed to parse key sequence: unterminated key <{key} in {ks}"
+                        ))
+                    }
+                }
+            }
+            sequence.push(from_key_string_to_event(&key).map_err(|_| {
+                anyhow::anyhow!("failed to parse key sequence: unknown/invalid key <{key}> in {ks}")
+            })?);
+        } else {
+            n_letters = if c.is_alphabetic() { n_letters + 1 } else { 0 };
+            // `-`, `_` and a run of 3 or more letters must be wrapped in `<>` inside
+            // a key sequence, so a misspelled key (e.g. `page_upp`, `enterr`) isn't
+            // parsed as a sequence
+            if c == '-' || c == '_' || n_letters >= 3 {
+                return Err(anyhow::anyhow!(
+                    "failed to parse key: unknown/invalid key {}",
+                    ks
+                ));
+            }
+            sequence.push(event::Event::Char(c));
+        }
+    }
+
+    if sequence.len() < 2 {
+        return Err(anyhow::anyhow!(
+            "failed to parse key: unknown/invalid key {}",
+            ks
+        ));
+    }
+    Ok(sequence)
 }
 
-config_parser_impl!(Keys);
+impl ConfigParser for Keys {
+    fn parse(&mut self, value: toml::Value) -> Result<()> {
+        let keys = value.try_into::<Keys>()?;
+        if keys.sequences.is_empty() && !keys.errors.is_empty() {
+            // none of the key strings is valid, keep the default keys
+            self.errors = keys.errors;
+        } else {
+            *self = keys;
+        }
+        Ok(())
+    }
+}
 
 impl<'de> de::Deserialize<'de> for Keys {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -365,89 +977,29 @@ impl<'de> de::Deserialize<'de> for Keys {
         /// - a single key string \[1\]
         /// - an array of multiple key strings
         ///
-        /// \[1\]: "key string" denotes the string representation of a key
+        /// \[1\]: "key string" denotes the string representation of a key or a key sequence
         enum StringOrVec {
             String(String),
             Vec(Vec<String>),
         }
 
-        /// a helper function that converts a key string into `cursive::event::Event`
-        fn from_key_string_to_event(ks: String) -> Result<event::Event> {
-            let chars: Vec<char> = ks.chars().collect();
-
-            let event = if chars.len() == 1 {
-                // a single character
-                event::Event::Char(chars[0])
-            } else if chars.len() == 3 && chars[1] == '-' {
-                // M-<c> for alt-<c> and C-<c> for ctrl-<c>, with <c> denotes a single character
-                match chars[0] {
-                    'C' => event::Event::CtrlChar(chars[2]),
-                    'M' => event::Event::AltChar(chars[2]),
-                    _ => {
-                        return Err(anyhow::anyhow!(
-                            "failed to parse key: unknown/invalid key {}",
-                            ks
-                        ))
-                    }
-                }
-            } else {
-                let key = match ks.as_str() {
-                    "enter" => event::Key::Enter,
-                    "tab" => event::Key::Tab,
-                    "backspace" => event::Key::Backspace,
-                    "esc" => event::Key::Esc,
-
-                    "left" => event::Key::Left,
-                    "right" => event::Key::Right,
-                    "up" => event::Key::Up,
-                    "down" => event::Key::Down,
-
-                    "ins" => event::Key::Ins,
-                    "del" => event::Key::Del,
-                    "home" => event::Key::Home,
-                    "end" => event::Key::End,
-                    "page_up" => event::Key::PageUp,
-                    "page_down" => event::Key::PageDown,
-
-                    "f1" => event::Key::F1,
-                    "f2" => event::Key::F2,
-                    "f3" => event::Key::F3,
-                    "f4" => event::Key::F4,
-                    "f5" => event::Key::F5,
-                    "f6" => event::Key::F6,
-                    "f7" => event::Key::F7,
-                    "f8" => event::Key::F8,
-                    "f9" => event::Key::F9,
-                    "f10" => event::Key::F10,
-                    "f11" => event::Key::F11,
-                    "f12" => event::Key::F12,
-
-                    _ => {
-                        return Err(anyhow::anyhow!(
-                            "failed to parse key: unknown/invalid key {}",
-                            ks
-                        ))
-                    }
-                };
-
-                event::Event::Key(key)
-            };
-
-            Ok(event)
-        }
-
         let key_strings = match StringOrVec::deserialize(deserializer)? {
             StringOrVec::String(v) => vec![v],
             StringOrVec::Vec(v) => v,
         };
 
-        let events = key_strings
-            .into_iter()
-            .map(from_key_string_to_event)
-            .collect::<Result<Vec<_>>>()
-            .map_err(serde::de::Error::custom)?;
+        let mut keys = Keys {
+            sequences: vec![],
+            errors: vec![],
+        };
+        for ks in key_strings {
+            match from_key_string_to_sequence(&ks) {
+                Ok(sequence) => keys.sequences.push(sequence),
+                Err(err) => keys.errors.push(err.to_string()),
+            }
+        }
 
-        Ok(Keys::new(events))
+        Ok(keys)
     }
 }
 
@@ -459,6 +1011,18 @@ pub fn get_scroll_keymap() -> &'static ScrollKeyMap {
     &super::get_config().keymap.scroll_keymap
 }
 
+pub fn get_find_keymap() -> &'static FindKeyMap {
+    &super::get_config().keymap.find_keymap
+}
+
+pub fn get_split_view_keymap() -> &'static SplitViewKeyMap {
+    &super::get_config().keymap.split_view_keymap
+}
+
+pub fn get_command_palette_keymap() -> &'static CommandPaletteKeyMap {
+    &super::get_config().keymap.command_palette_keymap
+}
+
 pub fn get_global_keymap() -> &'static GlobalKeyMap {
     &super::get_config().keymap.global_keymap
 }
@@ -482,3 +1046,118 @@ pub fn get_article_view_keymap() -> &'static ArticleViewKeyMap {
 pub fn get_link_dialog_keymap() -> &'static LinkDialogKeyMap {
     &super::get_config().keymap.link_dialog_keymap
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(key_strings: &[&str]) -> Keys {
+        Keys {
+            sequences: key_strings
+                .iter()
+                .map(|ks| from_key_string_to_sequence(ks).unwrap())
+                .collect(),
+            errors: vec![],
+        }
+    }
+
+    #[test]
+    fn parse_single_keys() {
+        assert_eq!(
+            from_key_string_to_sequence("g").unwrap(),
+            vec![event::Event::Char('g')]
+        );
+        assert_eq!(
+            from_key_string_to_sequence("C-x").unwrap(),
+            vec![event::Event::CtrlChar('x')]
+        );
+        assert_eq!(
+            from_key_string_to_sequence("page_up").unwrap(),
+            vec![event::Event::Key(event::Key::PageUp)]
+        );
+    }
+
+    #[test]
+    fn parse_key_sequences() {
+        assert_eq!(
+            from_key_string_to_sequence("gg").unwrap(),
+            vec![event::Event::Char('g'), event::Event::Char('g')]
+        );
+        assert_eq!(
+            from_key_string_to_sequence("<space>c").unwrap(),
+            vec![event::Event::Char(' '), event::Event::Char('c')]
+        );
+        assert_eq!(
+            from_key_string_to_sequence("g<enter>").unwrap(),
+            vec![
+                event::Event::Char('g'),
+                event::Event::Key(event::Key::Enter)
+            ]
+        );
+        // letters wrapped in `<>` reset the run of letters
+        assert_eq!(from_key_string_to_sequence("gg<g>gg").unwrap().len(), 5);
+        assert_eq!(
+            from_key_string_to_sequence("g<->").unwrap(),
+            vec![event::Event::Char('g'), event::Event::Char('-')]
+        );
+    }
+
+    #[test]
+    fn reject_misspelled_keys() {
+        for ks in ["enterr", "tabb", "page_upp", "pageup", "ggg", "g-", "a_b"] {
+            assert!(
+                from_key_string_to_sequence(ks).is_err(),
+                "`{ks}` should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn reject_unterminated_keys() {
+        for ks in ["<space", "g<enter", "gg<"] {
+            let err = from_key_string_to_sequence(ks).unwrap_err();
+            assert!(
+                err.to_string().contains("unterminated key"),
+                "unexpected error for `{ks}`: {err}"
+            );
+        }
+        assert!(from_key_string_to_sequence("g<enterr>").is_err());
+    }
+
+    #[test]
+    fn default_keymap_has_no_problems() {
+        assert_eq!(KeyMap::default().validate(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn validate_reports_conflicts() {
+        let mut keymap = KeyMap::default();
+        // `q` is bound to the global `quit` command, which is handled in every view
+        keymap.story_view_keymap.next_story = keys(&["q"]);
+        keymap.comment_view_keymap.next_comment = keys(&["qa"]);
+        let problems = keymap.validate();
+        assert_eq!(problems.len(), 2, "{problems:?}");
+        assert!(problems[0].contains("story_view_keymap.next_story"));
+        assert!(problems[0].contains("global_keymap.quit"));
+        assert!(problems[1].contains("comment_view_keymap.next_comment"));
+        assert!(problems[1].contains("one is a prefix of the other"));
+    }
+
+    #[test]
+    fn validate_ignores_keys_in_different_scopes() {
+        let mut keymap = KeyMap::default();
+        keymap.story_view_keymap.next_story = keys(&["x"]);
+        keymap.comment_view_keymap.next_comment = keys(&["x"]);
+        assert!(keymap.validate().is_empty());
+    }
+
+    #[test]
+    fn validate_reports_parse_errors() {
+        let mut keymap = KeyMap::default();
+        keymap.story_view_keymap.next_story =
+            Keys::deserialize(toml::Value::from(vec!["enterr", "j"])).unwrap();
+        let problems = keymap.validate();
+        assert_eq!(problems.len(), 1, "{problems:?}");
+        assert!(problems[0].starts_with("`story_view_keymap.next_story`: "));
+    }
+}
@@ -1,8 +1,12 @@
 // modules
+mod filter;
+mod highlight;
 mod keybindings;
 mod theme;
 
 // re-export
+pub use filter::*;
+pub use highlight::*;
 pub use keybindings::*;
 pub use theme::*;
 
@@ -14,12 +18,25 @@ use serde::Deserialize;
 pub struct Config {
     pub use_page_scrolling: bool,
     pub use_pacman_loading: bool,
+    pub use_infinite_scrolling: bool,
+    pub story_refresh_interval: Option<u64>,
+    pub show_user_metadata: bool,
+    pub use_comment_tree_guides: bool,
+    pub max_comment_indent_level: usize,
+    pub restore_session: bool,
+    pub use_split_view: bool,
+    pub split_view_ratio: f64,
+    pub split_view_min_width: usize,
     pub client_timeout: u64,
+    pub story_limit: Option<usize>,
+    pub search_limit: Option<usize>,
     pub url_open_command: Command,
     pub article_parse_command: Command,
 
     pub theme: theme::Theme,
     pub keymap: keybindings::KeyMap,
+    pub filter: filter::Filter,
+    pub highlight_rules: Vec<highlight::HighlightRule>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -29,7 +46,23 @@ pub struct Auth {
     pub password: String,
 }
 
+/// the minimum interval (in seconds) to refresh a story view in the background
+const MIN_STORY_REFRESH_INTERVAL: u64 = 10;
+
 impl Config {
+    /// clamp config options to their valid ranges, logging the clamped options
+    fn clamp_options(&mut self) {
+        if let Some(interval) = self.story_refresh_interval {
+            if interval < MIN_STORY_REFRESH_INTERVAL {
+                tracing::warn!(
+                    "`story_refresh_interval` ({interval}s) is less than the minimum interval, \
+                     use {MIN_STORY_REFRESH_INTERVAL}s instead"
+                );
+                self.story_refresh_interval = Some(MIN_STORY_REFRESH_INTERVAL);
+            }
+        }
+    }
+
     /// parse config from a file
     pub fn from_file<P>(file: P) -> anyhow::Result<Self>
     where
@@ -48,6 +81,15 @@ impl Default for Config {
         Config {
             use_page_scrolling: true,
             use_pacman_loading: true,
+            use_infinite_scrolling: false,
+            story_refresh_interval: None,
+            show_user_metadata: false,
+            use_comment_tree_guides: false,
+            max_comment_indent_level: 10,
+            restore_session: false,
+            use_split_view: false,
+            split_view_ratio: 0.4,
+            split_view_min_width: 160,
             #[cfg(all(unix, not(target_os = "macos")))]
             url_open_command: Command {
                 command: "xdg-open".to_string(),
@@ -68,8 +110,12 @@ impl Default for Config {
                 options: vec!["--format".to_string(), "html".to_string()],
             },
             client_timeout: 32,
+            story_limit: None,
+            search_limit: None,
             theme: theme::Theme::default(),
             keymap: keybindings::KeyMap::default(),
+            filter: filter::Filter::default(),
+            highlight_rules: vec![],
         }
     }
 }
@@ -114,10 +160,16 @@ pub fn load_config(config_file_str: &str) {
             );
             Config::default()
         }
-        Ok(config) => config,
+        Ok(mut config) => {
+            config.clamp_options();
+            config
+        }
     };
 
     tracing::info!("application's configurations: {:?}", config);
+    for problem in config.keymap.validate() {
+        tracing::warn!("invalid keymap configurations: {problem}");
+    }
     init_config(config);
 }
 
@@ -60,11 +60,19 @@ pub struct ComponentStyle {
     pub metadata: Style,
     pub current_story_tag: Style,
     pub username: Style,
+    /// style of the username of comments posted by the story's author
+    pub op_username: Style,
+    /// style of the local notes of users
+    pub user_note: Style,
     pub loading_bar: Style,
     pub ask_hn: Style,
     pub tell_hn: Style,
     pub show_hn: Style,
     pub launch_hn: Style,
+    pub rank_up: Style,
+    pub rank_down: Style,
+    pub new_story: Style,
+    pub filtered_item: Style,
 }
 
 impl Default for Palette {
@@ -124,6 +132,12 @@ impl Default for ComponentStyle {
             bold: Style::default().effect(Effect::Bold),
             metadata: Style::default().front(Color::parse("#828282")),
             username: Style::default().effect(Effect::Bold),
+            op_username: Style::default()
+                .front(Color::parse("#ff6600"))
+                .effect(Effect::Bold),
+            user_note: Style::default()
+                .front(Color::parse("magenta"))
+                .effect(Effect::Italic),
             loading_bar: Style::default()
                 .front(Color::parse("light yellow"))
                 .back(Color::parse("blue")),
@@ -139,6 +153,12 @@ impl Default for ComponentStyle {
             launch_hn: Style::default()
                 .front(Color::parse("green"))
                 .effect(Effect::Bold),
+            rank_up: Style::default().front(Color::parse("green")),
+            rank_down: Style::default().front(Color::parse("red")),
+            new_story: Style::default()
+                .front(Color::parse("yellow"))
+                .effect(Effect::Bold),
+            filtered_item: Style::default().front(Color::parse("light black")),
         }
     }
 }
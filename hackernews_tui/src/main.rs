@@ -4,17 +4,19 @@ pub mod config;
 pub mod model;
 pub mod parser;
 pub mod prelude;
+pub mod storage;
 pub mod utils;
 pub mod view;
 
 const DEFAULT_CONFIG_FILE: &str = "hn-tui.toml";
 const DEFAULT_AUTH_FILE: &str = "hn-auth.toml";
 const DEFAULT_LOG_FILE: &str = "hn-tui.log";
+const DEFAULT_DATA_FOLDER: &str = "hn-tui";
 
 use clap::*;
 use prelude::*;
 
-fn run(auth: Option<config::Auth>, start_id: Option<u32>) {
+fn run(auth: Option<config::Auth>, start_id: Option<u32>, restore: bool) {
     // setup HN Client
     let client = client::init_client();
 
@@ -26,7 +28,7 @@ fn run(auth: Option<config::Auth>, start_id: Option<u32>) {
     }
 
     // setup the application's UI
-    let s = view::init_ui(client, start_id);
+    let s = view::init_ui(client, start_id, restore);
 
     // use `cursive_buffered_backend` crate to fix the flickering issue
     // when using `cursive` with `crossterm_backend` (See https://github.com/gyscos/Cursive/issues/142)
@@ -37,6 +39,9 @@ fn run(auth: Option<config::Auth>, start_id: Option<u32>) {
     let mut app = CursiveRunner::new(s, buffered_backend);
 
     app.run();
+
+    // save the application's views to be restored in the next session
+    view::save_session(&mut app);
 }
 
 /// initialize application logging
@@ -63,7 +68,11 @@ fn init_logging(log_dir_str: &str) {
 }
 
 /// parse command line arguments
-fn parse_args(config_dir: std::path::PathBuf, cache_dir: std::path::PathBuf) -> ArgMatches {
+fn parse_args(
+    config_dir: std::path::PathBuf,
+    cache_dir: std::path::PathBuf,
+    data_dir: std::path::PathBuf,
+) -> ArgMatches {
     Command::new("hackernews-tui")
         .version(env!("CARGO_PKG_VERSION"))
         .about(env!("CARGO_PKG_DESCRIPTION"))
@@ -95,6 +104,15 @@ fn parse_args(config_dir: std::path::PathBuf, cache_dir: std::path::PathBuf) ->
                 .help("Path to a folder to store application's logs")
                 .next_line_help(true),
         )
+        .arg(
+            Arg::new("data")
+                .short('d')
+                .long("data")
+                .value_name("FOLDER")
+                .default_value(data_dir.join(DEFAULT_DATA_FOLDER).into_os_string())
+                .help("Path to a folder to store application's data (search history, etc)")
+                .next_line_help(true),
+        )
         .arg(
             Arg::new("start_id")
                 .short('i')
@@ -102,12 +120,21 @@ fn parse_args(config_dir: std::path::PathBuf, cache_dir: std::path::PathBuf) ->
                 .help("The Hacker News item's id to start the application with")
                 .next_line_help(true),
         )
+        .arg(
+            Arg::new("restore")
+                .short('r')
+                .long("restore")
+                .action(ArgAction::SetTrue)
+                .help("Restore the views of the last session")
+                .next_line_help(true),
+        )
         .get_matches()
 }
 
-fn init_app_dirs() -> (std::path::PathBuf, std::path::PathBuf) {
+fn init_app_dirs() -> (std::path::PathBuf, std::path::PathBuf, std::path::PathBuf) {
     let mut config_dir = dirs_next::config_dir().expect("failed to get user's config dir");
     let cache_dir = dirs_next::cache_dir().expect("failed to get user's cache dir");
+    let data_dir = dirs_next::data_dir().expect("failed to get user's data dir");
     let home_dir = dirs_next::home_dir().expect("failed to get user's home dir");
 
     // Try to find application's config file in the user's config dir.
@@ -116,7 +143,7 @@ fn init_app_dirs() -> (std::path::PathBuf, std::path::PathBuf) {
         config_dir = home_dir.join(".config");
     }
 
-    (config_dir, cache_dir)
+    (config_dir, cache_dir, data_dir)
 }
 
 fn init_auth(auth_file_str: &str) -> Option<config::Auth> {
@@ -130,8 +157,8 @@ fn init_auth(auth_file_str: &str) -> Option<config::Auth> {
 }
 
 fn main() {
-    let (config_dir, cache_dir) = init_app_dirs();
-    let args = parse_args(config_dir, cache_dir);
+    let (config_dir, cache_dir, data_dir) = init_app_dirs();
+    let args = parse_args(config_dir, cache_dir, data_dir);
 
     init_logging(
         args.get_one::<String>("log")
@@ -143,10 +170,16 @@ fn main() {
             .expect("`config` argument should have a default value"),
     );
 
+    storage::init_data_dir(
+        args.get_one::<String>("data")
+            .expect("`data` argument should have a default value"),
+    );
+
     let auth = init_auth(
         args.get_one::<String>("auth")
             .expect("`auth` argument should have a default value"),
     );
     let start_id = args.get_one::<u32>("start_id").cloned();
-    run(auth, start_id);
+    let restore = args.get_flag("restore") || config::get_config().restore_session;
+    run(auth, start_id, restore);
 }
@@ -2,7 +2,7 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 use serde::Deserialize;
 
-use crate::parser::parse_hn_html_text;
+use crate::parser::{parse_hn_html_text, LinkMarker};
 use crate::prelude::*;
 use crate::utils;
 
@@ -36,6 +36,21 @@ pub struct Comment {
     pub content: String,
 }
 
+/// A comment or a story whose text matches a search query
+#[derive(Debug, Clone)]
+pub struct TextMatch {
+    pub id: u32,
+    /// id of the story that the matched item belongs to
+    pub story_id: u32,
+    pub story_title: String,
+    /// article URL of the story that the matched item belongs to
+    pub url: String,
+    pub author: String,
+    pub time: u64,
+    /// the matched text, which may contain search matches wrapped inside `<em>` tags
+    pub content: String,
+}
+
 /// A Hacker News page data.
 ///
 /// The page data is mainly used to construct a comment view.
@@ -68,10 +83,31 @@ pub struct HnItem {
     pub level: usize,
     pub display_state: DisplayState,
     pub links: Vec<String>,
+    /// markers of the links in the item's `text`
+    link_markers: Vec<LinkMarker>,
+    pub author: String,
+    pub time: u64,
+    /// the number of the item's descendants
+    pub n_children: usize,
+    /// whether the item is a comment posted by the story's author
+    pub is_op: bool,
+    /// additional information about the item's author, rendered next to the author's username
+    pub user_annotation: Option<StyledString>,
+    /// the styled username of a comment's author, which is empty for a story
+    username: StyledString,
     text: StyledString,
     minimized_text: StyledString,
 }
 
+#[derive(Debug, Clone)]
+/// A Hacker News user
+pub struct User {
+    pub username: String,
+    /// the user's creation time
+    pub created: u64,
+    pub karma: u32,
+}
+
 #[derive(Debug, Clone)]
 pub enum DisplayState {
     Hidden,
@@ -117,14 +153,27 @@ impl From<Story> for HnItem {
             utils::combine_styled_strings([metadata.clone(), StyledString::plain("... (more)")])
         };
 
+        let content_len = result.content.source().len();
         let text =
             utils::combine_styled_strings([metadata, StyledString::plain("\n"), result.content]);
+        let link_markers = result
+            .link_markers
+            .iter()
+            .map(|m| m.shifted(text.source().len() - content_len))
+            .collect();
 
         HnItem {
             id: story.id,
             level: 0, // story is at level 0 by default
             display_state: DisplayState::Normal,
             links: result.links,
+            link_markers,
+            author: story.author,
+            time: story.time,
+            n_children: story.num_comments,
+            is_op: false,
+            user_annotation: None,
+            username: StyledString::new(),
             text,
             minimized_text,
         }
@@ -133,15 +182,31 @@ impl From<Story> for HnItem {
 
 impl From<Comment> for HnItem {
     fn from(comment: Comment) -> Self {
+        Self::from_comment(comment, None)
+    }
+}
+
+impl HnItem {
+    /// constructs an item from a comment in a page whose root item is posted by `root_author`
+    pub fn from_comment(comment: Comment, root_author: Option<&str>) -> Self {
         let component_style = &config::get_config_theme().component_style;
 
-        let metadata = utils::combine_styled_strings([
-            StyledString::styled(comment.author, component_style.username),
-            StyledString::styled(
-                format!(" {} ago ", utils::get_elapsed_time_as_text(comment.time)),
-                component_style.metadata,
-            ),
-        ]);
+        let is_op =
+            root_author.is_some_and(|author| !author.is_empty() && author == comment.author);
+        let username_style = if is_op {
+            component_style.op_username
+        } else {
+            component_style.username
+        };
+        let username_style = match config::get_user_highlight_style(&comment.author) {
+            Some(style) => Style::from(username_style).combine(style),
+            None => username_style.into(),
+        };
+        let username = StyledString::styled(&comment.author, username_style);
+        let metadata = StyledString::styled(
+            format!(" {} ago ", utils::get_elapsed_time_as_text(comment.time)),
+            component_style.metadata,
+        );
 
         // constructs a minimized text representing the collapsed comment's content
         let minimized_text = utils::combine_styled_strings([
@@ -155,14 +220,30 @@ impl From<Comment> for HnItem {
         // parse the comment's content
         let result = parse_hn_html_text(comment.content, Style::default(), 0);
 
-        let text =
-            utils::combine_styled_strings([metadata, StyledString::plain("\n"), result.content]);
+        let content_len = result.content.source().len();
+        let text = utils::combine_styled_strings([
+            metadata,
+            StyledString::plain("\n"),
+            config::highlight_keywords(result.content),
+        ]);
+        let link_markers = result
+            .link_markers
+            .iter()
+            .map(|m| m.shifted(text.source().len() - content_len))
+            .collect();
 
         HnItem {
             id: comment.id,
             level: comment.level,
             display_state: DisplayState::Normal,
             links: result.links,
+            link_markers,
+            author: comment.author,
+            time: comment.time,
+            n_children: comment.n_children,
+            is_op,
+            user_annotation: None,
+            username,
             text,
             minimized_text,
         }
@@ -172,7 +253,7 @@ impl From<Comment> for HnItem {
 impl Story {
     /// get the story's article URL.
     /// If the article URL is empty (in case of "AskHN" stories), fallback to the HN story's URL
-    pub fn get_url(&self) -> Cow<str> {
+    pub fn get_url(&self) -> Cow<'_, str> {
         if self.url.is_empty() {
             Cow::from(self.story_url())
         } else {
@@ -234,6 +315,12 @@ impl Story {
             }
         }
 
+        // decorate the story title based on the highlight rules
+        let mut parsed_title = config::highlight_keywords(parsed_title);
+        if let Some(style) = config::get_story_highlight_style(&self.url, &self.author) {
+            parsed_title = config::apply_style(parsed_title, style);
+        }
+
         parsed_title
     }
 
@@ -241,25 +328,81 @@ impl Story {
     pub fn plain_title(&self) -> String {
         self.title.replace("<em>", "").replace("</em>", "") // story's title from the search view can have `<em>` inside it
     }
+
+    /// Check if the story is filtered by the filtering rules
+    pub fn is_filtered(&self) -> bool {
+        let filter = &config::get_config().filter;
+        filter.match_title(&self.plain_title())
+            || filter.match_url(&self.url)
+            || filter.match_user(&self.author)
+    }
+}
+
+impl TextMatch {
+    /// Check if the matched item is filtered by the filtering rules
+    pub fn is_filtered(&self) -> bool {
+        let filter = &config::get_config().filter;
+        filter.match_title(&self.story_title)
+            || filter.match_url(&self.url)
+            || filter.match_user(&self.author)
+    }
 }
 
 impl HnItem {
+    /// gets the item's text without styles
+    pub fn plain_text(&self) -> String {
+        format!(
+            "{}{}{}",
+            self.username.source(),
+            self.get_user_annotation().source(),
+            self.text.source()
+        )
+    }
+
+    /// gets the user annotation, which is only rendered next to a comment author's username
+    fn get_user_annotation(&self) -> StyledString {
+        match self.user_annotation {
+            Some(ref annotation) if !self.username.is_empty() => annotation.clone(),
+            _ => StyledString::new(),
+        }
+    }
+
+    /// gets the text displayed before the item's state-based text
+    fn get_text_prefix(&self, vote_status: Option<bool>) -> StyledString {
+        let vote_text = match vote_status {
+            Some(true) => StyledString::styled("▲ ", config::get_config_theme().palette.green),
+            Some(false) => StyledString::plain("▲ "),
+            None => StyledString::plain(""),
+        };
+
+        utils::combine_styled_strings([
+            vote_text,
+            self.username.clone(),
+            self.get_user_annotation(),
+        ])
+    }
+
     /// gets the dispay text of the item, which depends on the item's states
     /// (e.g `vote_status`, `display_state`, etc)
     pub fn text(&self, vote_status: Option<bool>) -> StyledString {
-        let theme = config::get_config_theme();
-
         let text = match self.display_state {
-            DisplayState::Hidden => unreachable!("Hidden item's text shouldn't be accessed"),
             DisplayState::Minimized => self.minimized_text.clone(),
-            DisplayState::Normal => self.text.clone(),
-        };
-        let vote_text = match vote_status {
-            Some(true) => StyledString::styled("▲ ", theme.palette.green),
-            Some(false) => StyledString::plain("▲ "),
-            None => StyledString::plain(""),
+            // a hidden item is displayed with its full text once its parent is expanded
+            DisplayState::Normal | DisplayState::Hidden => self.text.clone(),
         };
 
-        utils::combine_styled_strings([vote_text, text])
+        utils::combine_styled_strings([self.get_text_prefix(vote_status), text])
+    }
+
+    /// gets the link markers in the item's display text (see `text`)
+    pub fn link_markers(&self, vote_status: Option<bool>) -> Vec<LinkMarker> {
+        if let DisplayState::Minimized = self.display_state {
+            return vec![];
+        }
+        let offset = self.get_text_prefix(vote_status).source().len();
+        self.link_markers
+            .iter()
+            .map(|m| m.shifted(offset))
+            .collect()
     }
 }
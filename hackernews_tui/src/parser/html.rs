@@ -7,13 +7,15 @@ use regex::Regex;
 /// It consists of multiple regexes representing different components.
 static HN_TEXT_RE: Lazy<Regex> = Lazy::new(|| {
     Regex::new(&format!(
-        "(({})|({})|({})|({})|({})|({}))",
+        "(({})|({})|({})|({})|({})|({})|({}))",
         // a regex matching a HTML paragraph
         r"<p>(?s)(?P<paragraph>(|[^>].*?))</p>",
         // a regex matching a paragraph quote (in markdown format)
         r"<p>(?s)(?P<quote>>[> ]*)(?P<text>.*?)</p>",
         // a regex matching an HTML italic string
         r"<i>(?s)(?P<italic>.*?)</i>",
+        // a regex matching a search match returned by HN Algolia search APIs
+        r"<em>(?s)(?P<em>.*?)</em>",
         // a regex matching a HTML code block (multiline)
         r"<pre><code>(?s)(?P<multiline_code>.*?)[\n]*</code></pre>",
         // a regex matching a single line code block (markdown format)
@@ -24,6 +26,25 @@ static HN_TEXT_RE: Lazy<Regex> = Lazy::new(|| {
     .unwrap()
 });
 
+/// A `[N]` marker referencing a link in a parsed HTML text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkMarker {
+    /// the byte range of the marker in the parsed content
+    pub range: std::ops::Range<usize>,
+    /// the number of the referenced link
+    pub number: usize,
+}
+
+impl LinkMarker {
+    /// shifts the marker's range by an offset
+    pub fn shifted(&self, offset: usize) -> Self {
+        Self {
+            range: self.range.start + offset..self.range.end + offset,
+            number: self.number,
+        }
+    }
+}
+
 /// Parsed result of a HTML text
 #[derive(Debug, Default)]
 pub struct HTMLTextParsedResult {
@@ -31,6 +52,8 @@ pub struct HTMLTextParsedResult {
     pub content: StyledString,
     /// a list of links inside the HTML document
     pub links: Vec<String>,
+    /// markers of the links in the parsed content
+    pub link_markers: Vec<LinkMarker>,
 }
 
 /// Parsed result of a HTML table
@@ -47,8 +70,11 @@ pub struct HTMLTableParsedResult {
 impl HTMLTextParsedResult {
     /// merge two HTML parsed results
     pub fn merge(&mut self, mut other: HTMLTextParsedResult) {
+        let offset = self.content.source().len();
         self.content.append(other.content);
         self.links.append(&mut other.links);
+        self.link_markers
+            .extend(other.link_markers.iter().map(|m| m.shifted(offset)));
     }
 }
 
@@ -124,10 +150,16 @@ fn parse(text: String, style: Style, base_link_id: usize) -> HTMLTextParsedResul
                 style.combine(component_style.link),
             );
             result.content.append_styled(" ", style);
-            result.content.append_styled(
-                format!("[{}]", result.links.len() + base_link_id),
-                style.combine(component_style.link_id),
-            );
+            let number = result.links.len() + base_link_id;
+            let marker = format!("[{number}]");
+            let start = result.content.source().len();
+            result.link_markers.push(LinkMarker {
+                range: start..start + marker.len(),
+                number,
+            });
+            result
+                .content
+                .append_styled(marker, style.combine(component_style.link_id));
         } else if let Some(m) = caps.name("multiline_code") {
             // HTML code block
             result.content.append_styled(
@@ -145,6 +177,11 @@ fn parse(text: String, style: Style, base_link_id: usize) -> HTMLTextParsedResul
             result
                 .content
                 .append_styled(m.as_str(), style.combine(component_style.italic));
+        } else if let Some(m) = caps.name("em") {
+            // search match
+            result
+                .content
+                .append_styled(m.as_str(), style.combine(component_style.matched_highlight));
         }
     }
 
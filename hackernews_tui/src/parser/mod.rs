@@ -1,5 +1,7 @@
 mod article;
 mod html;
+// a vendored copy of `rcdom`, some of its items are unused by the application
+#[allow(dead_code)]
 mod rcdom;
 
 pub use html::*;
@@ -133,7 +133,7 @@ impl Drop for Node {
         let mut nodes = std::mem::take(&mut *self.children.borrow_mut());
         while let Some(node) = nodes.pop() {
             let children = std::mem::take(&mut *node.children.borrow_mut());
-            nodes.extend(children.into_iter());
+            nodes.extend(children);
             if let NodeData::Element {
                 ref template_contents,
                 ..
@@ -261,10 +261,10 @@ impl TreeSink for RcDom {
     }
 
     fn elem_name<'a>(&self, target: &'a Handle) -> ExpandedName<'a> {
-        return match target.data {
+        match target.data {
             NodeData::Element { ref name, .. } => name.expanded(),
             _ => panic!("not an element!"),
-        };
+        }
     }
 
     fn create_element(
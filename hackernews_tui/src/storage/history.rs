@@ -0,0 +1,36 @@
+use super::{load_data, save_data, SessionView};
+use serde::{Deserialize, Serialize};
+
+const HISTORY_FILE: &str = "history.json";
+/// the maximum number of entries stored in the navigation history
+const HISTORY_LIMIT: usize = 200;
+
+/// A view opened by the user
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub view: SessionView,
+    pub title: String,
+    /// the time the view is opened
+    pub time: u64,
+}
+
+/// Get the navigation history, the most recently opened view comes first
+pub fn get_history() -> Vec<HistoryEntry> {
+    load_data(HISTORY_FILE)
+}
+
+/// Add a view to the navigation history
+pub fn add_history_entry(view: SessionView, title: String) {
+    let mut history = get_history();
+    history.retain(|e| e.view != view);
+    history.insert(
+        0,
+        HistoryEntry {
+            view,
+            title,
+            time: crate::utils::get_current_unix_time(),
+        },
+    );
+    history.truncate(HISTORY_LIMIT);
+    save_data(HISTORY_FILE, &history);
+}
@@ -0,0 +1,82 @@
+//! Application's persistent data, which is stored as JSON files inside the application's data directory.
+
+// modules
+mod history;
+mod search;
+mod session;
+mod story;
+mod thread;
+mod user;
+
+// re-export
+pub use history::*;
+pub use search::*;
+pub use session::*;
+pub use story::*;
+pub use thread::*;
+pub use user::*;
+
+use serde::{de::DeserializeOwned, Serialize};
+use std::path::PathBuf;
+
+static DATA_DIR: once_cell::sync::OnceCell<PathBuf> = once_cell::sync::OnceCell::new();
+
+/// initializes the application's data directory
+pub fn init_data_dir(data_dir_str: &str) {
+    let data_dir = PathBuf::from(data_dir_str);
+    if !data_dir.exists() {
+        if let Err(err) = std::fs::create_dir_all(&data_dir) {
+            tracing::error!("failed to create a data folder {data_dir_str}: {err}");
+        }
+    }
+
+    DATA_DIR.set(data_dir).unwrap_or_else(|_| {
+        panic!("failed to set up the application's data directory");
+    });
+}
+
+fn get_data_file_path(file: &str) -> Option<PathBuf> {
+    DATA_DIR.get().map(|dir| dir.join(file))
+}
+
+/// loads data from a file in the application's data directory.
+/// If failed to find/process the file, uses the default data.
+fn load_data<T>(file: &str) -> T
+where
+    T: DeserializeOwned + Default,
+{
+    let Some(path) = get_data_file_path(file) else {
+        return T::default();
+    };
+    if !path.exists() {
+        return T::default();
+    }
+
+    match std::fs::read_to_string(&path)
+        .map_err(anyhow::Error::from)
+        .and_then(|s| Ok(serde_json::from_str::<T>(&s)?))
+    {
+        Ok(data) => data,
+        Err(err) => {
+            tracing::warn!("failed to load data from {}: {err:#}", path.display());
+            T::default()
+        }
+    }
+}
+
+/// saves data into a file in the application's data directory
+fn save_data<T>(file: &str, data: &T)
+where
+    T: Serialize,
+{
+    let Some(path) = get_data_file_path(file) else {
+        return;
+    };
+
+    if let Err(err) = serde_json::to_string(data)
+        .map_err(anyhow::Error::from)
+        .and_then(|s| Ok(std::fs::write(&path, s)?))
+    {
+        tracing::warn!("failed to save data to {}: {err:#}", path.display());
+    }
+}
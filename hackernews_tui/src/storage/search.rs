@@ -0,0 +1,53 @@
+use super::{load_data, save_data};
+use crate::client::SearchTarget;
+use serde::{Deserialize, Serialize};
+
+const SEARCH_HISTORY_FILE: &str = "search_history.json";
+const SAVED_SEARCHES_FILE: &str = "saved_searches.json";
+/// the maximum number of queries stored in the search history
+const SEARCH_HISTORY_LIMIT: usize = 100;
+
+/// A named search query
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedSearch {
+    pub name: String,
+    /// the raw query, which may include search operators
+    pub query: String,
+    pub by_date: bool,
+    pub target: SearchTarget,
+}
+
+/// Get the search history, the most recent query comes first
+pub fn get_search_history() -> Vec<String> {
+    load_data(SEARCH_HISTORY_FILE)
+}
+
+/// Add a query to the search history
+pub fn add_search_history(query: &str) {
+    let query = query.trim();
+    if query.is_empty() {
+        return;
+    }
+
+    let mut history = get_search_history();
+    history.retain(|q| q != query);
+    history.insert(0, query.to_string());
+    history.truncate(SEARCH_HISTORY_LIMIT);
+    save_data(SEARCH_HISTORY_FILE, &history);
+}
+
+pub fn get_saved_searches() -> Vec<SavedSearch> {
+    load_data(SAVED_SEARCHES_FILE)
+}
+
+pub fn get_saved_search(name: &str) -> Option<SavedSearch> {
+    get_saved_searches().into_iter().find(|s| s.name == name)
+}
+
+/// Save a search, replacing any saved search with the same name
+pub fn save_search(search: SavedSearch) {
+    let mut searches = get_saved_searches();
+    searches.retain(|s| s.name != search.name);
+    searches.push(search);
+    save_data(SAVED_SEARCHES_FILE, &searches);
+}
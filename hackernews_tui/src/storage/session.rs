@@ -0,0 +1,49 @@
+use super::{load_data, save_data};
+use crate::client::{SearchTarget, StoryNumericFilters, StorySortMode};
+use serde::{Deserialize, Serialize};
+
+const SESSION_FILE: &str = "session.json";
+
+/// A view in the application's screen stack, which can be rebuilt in a new session
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "view", rename_all = "snake_case")]
+pub enum SessionView {
+    StoryView {
+        tag: String,
+        sort_mode: StorySortMode,
+        page: usize,
+        numeric_filters: StoryNumericFilters,
+    },
+    CommentView {
+        item_id: u32,
+    },
+    ArticleView {
+        url: String,
+    },
+    SearchView {
+        query: String,
+        by_date: bool,
+        target: SearchTarget,
+    },
+    UserView {
+        username: String,
+    },
+}
+
+/// The tabs of a session, each of which is a list of views ordered
+/// from the bottom to the top of the tab's screen stack
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Session {
+    pub tabs: Vec<Vec<SessionView>>,
+    pub active_tab: usize,
+}
+
+/// Get the last session
+pub fn get_session() -> Session {
+    load_data(SESSION_FILE)
+}
+
+/// Save the current session
+pub fn save_session(session: &Session) {
+    save_data(SESSION_FILE, session)
+}
@@ -0,0 +1,77 @@
+use super::{load_data, save_data};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+const STORY_SNAPSHOTS_FILE: &str = "story_snapshots.json";
+/// the maximum number of snapshots stored for each story
+const SNAPSHOT_LIMIT: usize = 16;
+/// the duration (in seconds) to keep a snapshot
+const SNAPSHOT_RETENTION: u64 = 2 * 24 * 60 * 60;
+/// the time window (in seconds) to compute a story's score velocity
+const VELOCITY_WINDOW: u64 = 60 * 60;
+
+/// A snapshot of a story's rank and points at a specific time
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct StorySnapshot {
+    pub time: u64,
+    pub rank: usize,
+    pub points: u32,
+}
+
+/// Snapshots of stories keyed by story id, the oldest snapshot comes first
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct StorySnapshots(HashMap<u32, Vec<StorySnapshot>>);
+
+impl StorySnapshots {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Get the latest snapshot of a story
+    pub fn latest(&self, story_id: u32) -> Option<&StorySnapshot> {
+        self.0.get(&story_id)?.last()
+    }
+
+    /// Get the velocity (points per hour) of a story's score based on its snapshots.
+    ///
+    /// The velocity is computed against the oldest snapshot within the last hour,
+    /// or the latest snapshot if no such snapshot exists.
+    pub fn points_velocity(&self, story_id: u32, points: u32, time: u64) -> Option<f64> {
+        let snapshots = self.0.get(&story_id)?;
+        let snapshot = snapshots
+            .iter()
+            .find(|s| time.saturating_sub(s.time) <= VELOCITY_WINDOW)
+            .or(snapshots.last())?;
+
+        // the elapsed time is too short to give a meaningful velocity
+        let elapsed = time.saturating_sub(snapshot.time);
+        if elapsed < 60 {
+            return None;
+        }
+        Some((points as f64 - snapshot.points as f64) * 3600.0 / elapsed as f64)
+    }
+}
+
+/// Get the stored story snapshots
+pub fn get_story_snapshots() -> StorySnapshots {
+    load_data(STORY_SNAPSHOTS_FILE)
+}
+
+/// Add snapshots of stories taken at `time`, each of which is represented by a tuple of
+/// story id, rank and points. Old snapshots are discarded.
+pub fn add_story_snapshots(stories: impl Iterator<Item = (u32, usize, u32)>, time: u64) {
+    let mut snapshots = get_story_snapshots();
+    for (id, rank, points) in stories {
+        let history = snapshots.0.entry(id).or_default();
+        history.push(StorySnapshot { time, rank, points });
+        if history.len() > SNAPSHOT_LIMIT {
+            history.drain(..history.len() - SNAPSHOT_LIMIT);
+        }
+    }
+
+    snapshots.0.retain(|_, history| {
+        history.retain(|s| time.saturating_sub(s.time) <= SNAPSHOT_RETENTION);
+        !history.is_empty()
+    });
+    save_data(STORY_SNAPSHOTS_FILE, &snapshots);
+}
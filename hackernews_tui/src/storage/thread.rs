@@ -0,0 +1,37 @@
+use super::{load_data, save_data};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+const THREAD_STATES_FILE: &str = "thread_states.json";
+/// the maximum number of threads whose states are stored
+const THREAD_STATE_LIMIT: usize = 500;
+
+/// The state of a comment thread, which is restored when the thread is reopened
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ThreadState {
+    pub collapsed_item_ids: HashSet<u32>,
+    pub focused_item_id: Option<u32>,
+    /// the time the state is saved
+    pub time: u64,
+}
+
+/// Get the state of the comment thread of an item
+pub fn get_thread_state(item_id: u32) -> Option<ThreadState> {
+    load_data::<HashMap<u32, ThreadState>>(THREAD_STATES_FILE).remove(&item_id)
+}
+
+/// Save the state of the comment thread of an item.
+/// If the number of stored states exceeds the limit, the oldest states are removed.
+pub fn save_thread_state(item_id: u32, state: ThreadState) {
+    let mut states = load_data::<HashMap<u32, ThreadState>>(THREAD_STATES_FILE);
+    states.insert(item_id, state);
+
+    if states.len() > THREAD_STATE_LIMIT {
+        let mut times = states.values().map(|s| s.time).collect::<Vec<_>>();
+        times.sort_unstable_by(|x, y| y.cmp(x));
+        let min_time = times[THREAD_STATE_LIMIT - 1];
+        states.retain(|_, s| s.time >= min_time);
+    }
+
+    save_data(THREAD_STATES_FILE, &states);
+}
@@ -0,0 +1,21 @@
+use super::{load_data, save_data};
+use std::collections::HashMap;
+
+const USER_NOTES_FILE: &str = "user_notes.json";
+
+/// Get local notes of users, keyed by the users' usernames
+pub fn get_user_notes() -> HashMap<String, String> {
+    load_data(USER_NOTES_FILE)
+}
+
+/// Set the local note of a user. An empty note removes the user's note.
+pub fn set_user_note(username: &str, note: &str) {
+    let note = note.trim();
+    let mut notes = get_user_notes();
+    if note.is_empty() {
+        notes.remove(username);
+    } else {
+        notes.insert(username.to_string(), note.to_string());
+    }
+    save_data(USER_NOTES_FILE, &notes);
+}
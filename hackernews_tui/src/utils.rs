@@ -26,6 +26,59 @@ pub fn from_day_offset_to_time_offset_in_secs(day_offset: u32) -> u64 {
     day_in_secs * (day_offset as u64)
 }
 
+/// Convert a date in the `YYYY-MM-DD` format into a unix time (in seconds)
+/// representing the start of the date (UTC).
+///
+/// The conversion is based on the `days_from_civil` algorithm
+/// described in http://howardhinnant.github.io/date_algorithms.html.
+pub fn from_date_to_unix_time(date: &str) -> Option<u64> {
+    let mut parts = date.splitn(3, '-').map(|x| x.parse::<i64>().ok());
+    let (y, m, d) = (parts.next()??, parts.next()??, parts.next()??);
+    if !(1..=12).contains(&m) || !(1..=31).contains(&d) {
+        return None;
+    }
+
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400;
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146097 + doe - 719468;
+
+    let time = u64::try_from(days).ok()? * from_day_offset_to_time_offset_in_secs(1);
+    // reject invalid dates such as `2024-02-31`
+    if from_unix_time_to_date(time) == date {
+        Some(time)
+    } else {
+        None
+    }
+}
+
+/// Convert a unix time (in seconds) into a date in the `YYYY-MM-DD` format (UTC).
+///
+/// The conversion is based on the `civil_from_days` algorithm
+/// described in http://howardhinnant.github.io/date_algorithms.html.
+pub fn from_unix_time_to_date(time: u64) -> String {
+    let z = (time / from_day_offset_to_time_offset_in_secs(1)) as i64 + 719468;
+    let era = z / 146097;
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = yoe + era * 400 + i64::from(m <= 2);
+    format!("{y:04}-{m:02}-{d:02}")
+}
+
+/// Get the current time in seconds since the Unix epoch
+pub fn get_current_unix_time() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
 /// Calculate the elapsed time and return the result
 /// in an appropriate format depending on the duration
 pub fn get_elapsed_time_as_text(time: u64) -> String {
@@ -37,6 +90,28 @@ pub fn get_elapsed_time_as_text(time: u64) -> String {
     get_time_offset_in_text(offset)
 }
 
+/// Get the domain of a given URL
+pub fn get_url_domain(url: &str) -> Option<String> {
+    url::Url::parse(url).ok()?.domain().map(|d| d.to_string())
+}
+
+/// Check if a domain is the same as or a subdomain of another domain
+pub fn match_domain(domain: &str, parent_domain: &str) -> bool {
+    domain == parent_domain
+        || domain
+            .strip_suffix(parent_domain)
+            .is_some_and(|s| s.ends_with('.'))
+}
+
+/// Check if a string is a valid Hacker News username, which only contains
+/// letters, digits, dashes and underscores and is safe to be used in URLs
+pub fn is_valid_username(username: &str) -> bool {
+    !username.is_empty()
+        && username
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
 /// A simple URL shortening function that reduces the
 /// URL length if it exceeds a given threshold
 pub fn shorten_url(url: &str) -> String {
@@ -66,3 +141,44 @@ where
 pub fn decode_html(s: &str) -> String {
     html_escape::decode_html_entities(s).into()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn date_to_unix_time() {
+        assert_eq!(from_date_to_unix_time("1970-01-01"), Some(0));
+        assert_eq!(from_date_to_unix_time("2024-01-01"), Some(1704067200));
+        assert_eq!(from_date_to_unix_time("2024-02-29"), Some(1709164800));
+    }
+
+    #[test]
+    fn date_round_trip() {
+        for date in ["1970-01-01", "2000-02-29", "2023-12-31", "2024-02-29"] {
+            let time = from_date_to_unix_time(date).unwrap();
+            assert_eq!(from_unix_time_to_date(time), date);
+        }
+    }
+
+    #[test]
+    fn invalid_dates() {
+        for date in [
+            "2024-02-31",
+            "2023-02-29",
+            "2024-04-31",
+            "2024-13-01",
+            "2024-00-10",
+            "2024-1-1",
+            "1969-12-31",
+            "2024-01",
+            "today",
+        ] {
+            assert_eq!(
+                from_date_to_unix_time(date),
+                None,
+                "{date} should be invalid"
+            );
+        }
+    }
+}
@@ -1,15 +1,29 @@
-use super::{async_view, help_view::HasHelpView, link_dialog, traits::*, utils};
+use super::{
+    async_view, command_palette,
+    find_bar::{self, FindBar, FindBarContainer, OnFindEventView},
+    help_view::HasHelpView,
+    key_sequence, link_dialog, session,
+    traits::*,
+    utils,
+};
 use crate::prelude::*;
+use crate::storage;
 
 /// ArticleView is a View used to display the content of a web page in reader mode
 pub struct ArticleView {
     article: Article,
     links: Vec<String>,
     width: usize,
+    /// the parsed article's content without highlighted matches
+    content: StyledString,
 
     view: ScrollView<LinearLayout>,
 
-    raw_command: String,
+    find_bar: FindBar,
+    /// the id of the content's span containing the current match
+    current_span_id: Option<usize>,
+    /// whether to scroll to the current match in the next layout
+    scroll_to_match: bool,
 }
 
 impl ViewWrapper for ArticleView {
@@ -33,7 +47,26 @@ impl ViewWrapper for ArticleView {
             }
         }
 
-        self.with_view_mut(|v| v.layout(size));
+        let view_size = size.saturating_sub((0, self.find_bar.height()));
+        self.with_view_mut(|v| v.layout(view_size));
+        self.find_bar.layout(size);
+
+        if self.scroll_to_match {
+            self.scroll_to_match = false;
+            self.scroll_to_current_match(view_size);
+        }
+    }
+
+    fn wrap_draw(&self, printer: &Printer) {
+        let height = self.find_bar.height();
+        self.view
+            .draw(&printer.cropped(printer.size.saturating_sub((0, height))));
+        self.find_bar.draw(printer);
+    }
+
+    fn wrap_required_size(&mut self, req: Vec2) -> Vec2 {
+        let height = self.find_bar.height();
+        self.view.required_size(req.saturating_sub((0, height))) + (0, height)
     }
 
     fn wrap_take_focus(&mut self, _: Direction) -> Result<EventResult, CannotFocus> {
@@ -65,14 +98,17 @@ impl ArticleView {
             article,
             links: vec![],
             width: 0,
+            content: StyledString::new(),
 
             view,
-            raw_command: "".to_string(),
+
+            find_bar: FindBar::new(),
+            current_span_id: None,
+            scroll_to_match: false,
         }
     }
 
-    /// Update the content of the article
-    pub fn set_article_content(&mut self, new_content: StyledString) {
+    fn get_content_view_mut(&mut self) -> &mut TextView {
         self.view
             .get_inner_mut()
             .get_child_mut(2)
@@ -80,12 +116,80 @@ impl ArticleView {
             .downcast_mut::<PaddedView<TextView>>()
             .expect("The 3rd child of the article view should be a padded text view")
             .get_inner_mut()
-            .set_content(new_content)
+    }
+
+    /// Update the content of the article
+    pub fn set_article_content(&mut self, new_content: StyledString) {
+        self.content = new_content;
+        let n_matches = self.render_content(self.find_bar.current_match());
+        let current_match = self.find_bar.current_match();
+        self.find_bar.set_matches(n_matches, current_match);
+    }
+
+    /// Render the article's content with highlighted matches of the find query.
+    /// Return the number of matches.
+    fn render_content(&mut self, current_match: Option<usize>) -> usize {
+        let result = find_bar::highlight_matches(
+            self.content.clone(),
+            &self.find_bar.query(),
+            current_match,
+        );
+        self.current_span_id = result.current_span_id;
+        self.get_content_view_mut().set_content(result.content);
+        result.n_matches
+    }
+
+    /// Scroll to the row containing the current match
+    fn scroll_to_current_match(&mut self, size: Vec2) {
+        let Some(span_id) = self.current_span_id else {
+            return;
+        };
+
+        // the width of the article's content excluding the paddings and the scroll bar
+        let width = size.x.saturating_sub(4);
+        let row = {
+            let content = self.get_content_view_mut().get_content();
+            lines::spans::LinesIterator::new(&*content, width)
+                .position(|row| row.segments.iter().any(|s| s.span_id == span_id))
+        };
+        let Some(row) = row else {
+            return;
+        };
+
+        // the height of the article's title and description plus the top padding
+        let mut header_height = 1;
+        for i in 0..2 {
+            if let Some(view) = self.view.get_inner_mut().get_child_mut(i) {
+                header_height += view.required_size(size).y;
+            }
+        }
+        self.view
+            .set_offset((0, (header_height + row).saturating_sub(size.y / 2)));
     }
 
     inner_getters!(self.view: ScrollView<LinearLayout>);
 }
 
+impl FindBarContainer for ArticleView {
+    fn get_find_bar(&self) -> &FindBar {
+        &self.find_bar
+    }
+
+    fn get_find_bar_mut(&mut self) -> &mut FindBar {
+        &mut self.find_bar
+    }
+
+    fn find_matches(&mut self, _query: &str) -> (usize, Option<usize>) {
+        let n_matches = self.render_content(None);
+        (n_matches, Some(0))
+    }
+
+    fn focus_match(&mut self, i: Option<usize>) {
+        self.render_content(i);
+        self.scroll_to_match = i.is_some();
+    }
+}
+
 impl ScrollViewContainer for ArticleView {
     type ScrollInner = LinearLayout;
 
@@ -98,32 +202,10 @@ impl ScrollViewContainer for ArticleView {
     }
 }
 
-fn construct_article_main_view(
-    client: &'static client::HNClient,
-    article: Article,
-) -> OnEventView<ArticleView> {
-    let is_suffix_key = |c: &Event| -> bool {
-        let article_view_keymap = config::get_article_view_keymap();
-        article_view_keymap.open_link_in_browser.has_event(c)
-            || article_view_keymap.open_link_in_article_view.has_event(c)
-    };
-
+fn construct_article_main_view(client: &'static client::HNClient, article: Article) -> impl View {
     let article_view_keymap = config::get_article_view_keymap().clone();
 
-    OnEventView::new(ArticleView::new(article))
-        .on_pre_event_inner(EventTrigger::from_fn(|_| true), move |s, e| {
-            match *e {
-                Event::Char(c) if c.is_ascii_digit() => {
-                    s.raw_command.push(c);
-                }
-                _ => {
-                    if !is_suffix_key(e) {
-                        s.raw_command.clear();
-                    }
-                }
-            };
-            None
-        })
+    let view = OnEventView::new(ArticleView::new(article))
         .on_pre_event_inner(article_view_keymap.open_link_dialog, move |s, _| {
             Some(EventResult::with_cb({
                 let links = s.links.clone();
@@ -133,22 +215,14 @@ fn construct_article_main_view(
             }))
         })
         .on_pre_event_inner(article_view_keymap.open_link_in_browser, |s, _| {
-            match s.raw_command.parse::<usize>() {
-                Ok(num) => {
-                    s.raw_command.clear();
-                    utils::open_ith_link_in_browser(&s.links, num)
-                }
-                Err(_) => None,
-            }
+            let num = key_sequence::get_count()?;
+            utils::open_ith_link_in_browser(&s.links, num)
         })
         .on_pre_event_inner(
             article_view_keymap.open_link_in_article_view,
-            move |s, _| match s.raw_command.parse::<usize>() {
-                Ok(num) => {
-                    s.raw_command.clear();
-                    utils::open_ith_link_in_article_view(client, &s.links, num)
-                }
-                Err(_) => None,
+            move |s, _| {
+                let num = key_sequence::get_count()?;
+                utils::open_ith_link_in_article_view(client, &s.links, num)
             },
         )
         .on_pre_event_inner(article_view_keymap.open_article_in_browser, |s, _| {
@@ -158,7 +232,18 @@ fn construct_article_main_view(
         .on_pre_event(config::get_global_keymap().open_help_dialog.clone(), |s| {
             s.add_layer(ArticleView::construct_on_event_help_view())
         })
-        .on_scroll_events()
+        .on_pre_event(
+            config::get_global_keymap().open_command_palette.clone(),
+            move |s| {
+                s.add_layer(command_palette::construct_command_palette(
+                    client,
+                    ArticleView::command_groups(),
+                ))
+            },
+        )
+        .on_scroll_events();
+
+    OnEventView::new(view).on_find_events()
 }
 
 /// Construct an article view of an article
@@ -183,5 +268,8 @@ pub fn construct_and_add_new_article_view(
     url: &str,
 ) {
     let async_view = async_view::construct_article_view_async(client, s, url);
-    s.screen_mut().add_transparent_layer(Layer::new(async_view))
+    let session_view = storage::SessionView::ArticleView {
+        url: url.to_string(),
+    };
+    session::add_session_layer(s, session_view, Layer::new(async_view))
 }
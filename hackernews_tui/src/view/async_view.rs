@@ -1,6 +1,7 @@
-use super::{article_view, comment_view, result_view::ResultView, story_view};
+use super::{article_view, comment_view, result_view::ResultView, session, story_view};
 use crate::client;
 use crate::prelude::*;
+use crate::storage;
 use anyhow::Context;
 use cursive_aligned_view::Alignable;
 use cursive_async_view::AsyncView;
@@ -9,12 +10,31 @@ pub fn construct_comment_view_async(
     siv: &mut Cursive,
     client: &'static client::HNClient,
     item_id: u32,
+    focus_item_id: Option<u32>,
 ) -> impl View {
+    let cb_sink = siv.cb_sink().clone();
+    let record_history = !session::is_restoring_session(siv);
     AsyncView::new_with_bg_creator(siv, move || Ok(client.get_page_data(item_id)), {
         move |result: Result<_>| {
             ResultView::new(
                 result.with_context(|| format!("failed to load comments from item (id={item_id})")),
-                |data| comment_view::construct_comment_view(client, data),
+                |data: PageData| {
+                    if record_history {
+                        storage::add_history_entry(
+                            storage::SessionView::CommentView {
+                                item_id: data.root_item.id,
+                            },
+                            format!("Comment View - {}", data.title),
+                        );
+                    }
+                    comment_view::construct_comment_view(
+                        client,
+                        data,
+                        focus_item_id,
+                        cb_sink.clone(),
+                        true,
+                    )
+                },
             )
         }
     })
@@ -31,6 +51,8 @@ pub fn construct_story_view_async(
     page: usize,
     numeric_filters: client::StoryNumericFilters,
 ) -> impl View {
+    let cb_sink = siv.cb_sink().clone();
+    let record_history = !session::is_restoring_session(siv);
     AsyncView::new_with_bg_creator(
         siv,
         move || Ok(client.get_stories_by_tag(tag, sort_mode, page, numeric_filters)),
@@ -42,7 +64,22 @@ pub fn construct_story_view_async(
                     )
                 }),
                 |stories| {
-                    story_view::construct_story_view(stories, client, tag, sort_mode, page, numeric_filters)
+                    if record_history {
+                        storage::add_history_entry(
+                            storage::SessionView::StoryView {
+                                tag: tag.to_string(),
+                                sort_mode,
+                                page,
+                                numeric_filters,
+                            },
+                            format!(
+                                "Story View - {tag}{}, page {}",
+                                story_view::get_sort_mode_desc(sort_mode),
+                                page + 1
+                            ),
+                        );
+                    }
+                    story_view::construct_story_view(stories, client, tag, sort_mode, page, numeric_filters, cb_sink.clone())
                 },
             )
         },
@@ -63,6 +100,7 @@ pub fn construct_article_view_async(
          Please review your configuration as described in the below link, and try again\n\
          \"https://github.com/aome510/hackernews-TUI/blob/main/docs/config.md#article-parse-command\"",
         article_url);
+    let record_history = !session::is_restoring_session(siv);
 
     AsyncView::new_with_bg_creator(
         siv,
@@ -73,6 +111,14 @@ pub fn construct_article_view_async(
         move |result| {
             let err_context = err_context.clone();
             ResultView::new(result.with_context(|| err_context), |article| {
+                if record_history {
+                    storage::add_history_entry(
+                        storage::SessionView::ArticleView {
+                            url: article.url.clone(),
+                        },
+                        format!("Article View - {}", article.title),
+                    );
+                }
                 article_view::construct_article_view(client, article)
             })
         },
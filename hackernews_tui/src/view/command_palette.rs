@@ -0,0 +1,463 @@
+use super::{
+    comment_view,
+    help_view::{Command, CommandGroup},
+    search_view,
+    text_view::{self, EditableTextView},
+    utils,
+};
+use crate::prelude::*;
+use crate::storage;
+
+type CommandPaletteContent = LinearLayout;
+
+/// A command taking an argument, which can be run in any view
+#[derive(Debug, Clone, Copy)]
+enum ArgumentCommand {
+    Open,
+    User,
+    Search,
+}
+
+/// An item listed in the command palette
+enum PaletteItem {
+    /// a command triggered by a key event, represented by its index in the palette's commands
+    Command(usize),
+    /// a command taking an argument, which can only be run once the argument is specified
+    ArgumentCommand(ArgumentCommand, Option<String>),
+}
+
+/// CommandPalette is a dialog to find and run the commands of a view.
+///
+/// Commands are filtered by fuzzy matching the palette's query against their names and descriptions.
+/// Commands with arguments can be run by entering them directly, e.g. `open 12345`.
+pub struct CommandPalette {
+    view: Dialog,
+    commands: Vec<Command>,
+    /// the items matching the palette's query
+    items: Vec<PaletteItem>,
+    focus: usize,
+}
+
+impl ArgumentCommand {
+    const ALL: [ArgumentCommand; 3] = [Self::Open, Self::User, Self::Search];
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Open => "open",
+            Self::User => "user",
+            Self::Search => "search",
+        }
+    }
+
+    fn arg_desc(self) -> &'static str {
+        match self {
+            Self::Open => "{item_id}",
+            Self::User => "{username}",
+            Self::Search => "{query}",
+        }
+    }
+
+    fn desc(self) -> &'static str {
+        match self {
+            Self::Open => "Open the comment view of an item",
+            Self::User => "Show the information of a user",
+            Self::Search => "Search stories matching a query",
+        }
+    }
+
+    /// parses a command line, e.g. `open 12345`, into a command and its argument
+    fn parse(line: &str) -> Option<(Self, &str)> {
+        let (name, arg) = line.trim_start().trim_start_matches(':').split_once(' ')?;
+        let arg = arg.trim();
+        if arg.is_empty() {
+            return None;
+        }
+        Self::ALL
+            .into_iter()
+            .find(|c| c.name() == name)
+            .map(|c| (c, arg))
+    }
+
+    fn run(self, s: &mut Cursive, client: &'static client::HNClient, arg: &str) {
+        match self {
+            Self::Open => match arg.trim_start_matches('#').parse::<u32>() {
+                Ok(item_id) => comment_view::construct_and_add_new_comment_view(
+                    s, client, item_id, None, false,
+                ),
+                Err(_) => s.add_layer(utils::construct_info_dialog(
+                    "Error",
+                    format!("Invalid item id: {arg}"),
+                )),
+            },
+            Self::User => add_user_dialog(s, client, arg.to_string()),
+            Self::Search => search_view::construct_and_add_new_search_view(
+                s,
+                client,
+                Some(storage::SavedSearch {
+                    name: String::new(),
+                    query: arg.to_string(),
+                    by_date: false,
+                    target: client::SearchTarget::Stories,
+                }),
+            ),
+        }
+    }
+}
+
+impl CommandPalette {
+    pub fn new(groups: Vec<CommandGroup>) -> Self {
+        // only commands triggered by a key event can be run from the palette
+        let commands = groups
+            .into_iter()
+            .flat_map(CommandGroup::into_commands)
+            .filter(|c| c.get_event().is_some())
+            .collect();
+
+        let view = Dialog::around(
+            LinearLayout::vertical()
+                .child(
+                    LinearLayout::horizontal()
+                        .child(TextView::new(StyledString::styled(
+                            ":",
+                            config::get_config_theme().component_style.bold,
+                        )))
+                        .child(EditableTextView::new()),
+                )
+                .child(LinearLayout::vertical().scrollable())
+                .fixed_width(80)
+                .max_height(24),
+        )
+        .title("Command Palette");
+
+        let mut palette = Self {
+            view,
+            commands,
+            items: vec![],
+            focus: 0,
+        };
+        palette.update_items();
+        palette
+    }
+
+    fn content(&self) -> &CommandPaletteContent {
+        self.view
+            .get_content()
+            .downcast_ref::<ResizedView<ResizedView<CommandPaletteContent>>>()
+            .expect("the command palette's content should have `CommandPaletteContent` type")
+            .get_inner()
+            .get_inner()
+    }
+
+    fn content_mut(&mut self) -> &mut CommandPaletteContent {
+        self.view
+            .get_content_mut()
+            .downcast_mut::<ResizedView<ResizedView<CommandPaletteContent>>>()
+            .expect("the command palette's content should have `CommandPaletteContent` type")
+            .get_inner_mut()
+            .get_inner_mut()
+    }
+
+    pub fn get_query_view_mut(&mut self) -> Option<&mut EditableTextView> {
+        self.content_mut()
+            .get_child_mut(0)?
+            .downcast_mut::<LinearLayout>()?
+            .get_child_mut(1)?
+            .downcast_mut::<EditableTextView>()
+    }
+
+    fn get_query(&self) -> String {
+        self.content()
+            .get_child(0)
+            .and_then(|v| v.downcast_ref::<LinearLayout>())
+            .and_then(|v| v.get_child(1))
+            .and_then(|v| v.downcast_ref::<EditableTextView>())
+            .map(|v| v.get_text())
+            .unwrap_or_default()
+    }
+
+    fn get_list_view_mut(&mut self) -> Option<&mut ScrollView<LinearLayout>> {
+        self.content_mut()
+            .get_child_mut(1)?
+            .downcast_mut::<ScrollView<LinearLayout>>()
+    }
+
+    /// updates the palette's items based on the palette's query
+    pub fn update_items(&mut self) {
+        let query = self.get_query();
+
+        self.items = match ArgumentCommand::parse(&query) {
+            Some((command, arg)) => {
+                vec![PaletteItem::ArgumentCommand(command, Some(arg.to_string()))]
+            }
+            None => {
+                let query = query.trim_start_matches(':');
+                let mut matches = self
+                    .commands
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, c)| {
+                        let text = format!("{} {}", c.get_name().unwrap_or_default(), c.get_desc());
+                        fuzzy_match(query, &text).map(|score| (score, PaletteItem::Command(i)))
+                    })
+                    .chain(ArgumentCommand::ALL.into_iter().filter_map(|c| {
+                        let text = format!("{} {}", c.name(), c.desc());
+                        fuzzy_match(query, &text)
+                            .map(|score| (score, PaletteItem::ArgumentCommand(c, None)))
+                    }))
+                    .collect::<Vec<_>>();
+                // the sort is stable, so items with the same score keep their original order
+                matches.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+                matches.into_iter().map(|(_, item)| item).collect()
+            }
+        };
+
+        let texts = self
+            .items
+            .iter()
+            .map(|item| self.get_item_text(item))
+            .collect::<Vec<_>>();
+        if let Some(list) = self.get_list_view_mut() {
+            let list = list.get_inner_mut();
+            list.clear();
+            for text in texts {
+                list.add_child(text_view::TextView::new(text));
+            }
+        }
+        self.set_focus(0);
+    }
+
+    fn get_item_text(&self, item: &PaletteItem) -> StyledString {
+        let component_style = &config::get_config_theme().component_style;
+
+        match item {
+            PaletteItem::Command(i) => {
+                let command = &self.commands[*i];
+                let mut text = StyledString::new();
+                if let Some(name) = command.get_name() {
+                    text.append_styled(format!("{name} "), component_style.bold);
+                }
+                text.append_plain(command.get_desc());
+                text.append_styled(
+                    format!(" ({})", command.get_keys_desc()),
+                    component_style.metadata,
+                );
+                text
+            }
+            PaletteItem::ArgumentCommand(command, arg) => {
+                let mut text = StyledString::styled(
+                    format!(
+                        "{} {} ",
+                        command.name(),
+                        arg.as_deref().unwrap_or(command.arg_desc())
+                    ),
+                    component_style.bold,
+                );
+                text.append_plain(command.desc());
+                text
+            }
+        }
+    }
+
+    /// focuses the `id`-th item of the palette
+    pub fn set_focus(&mut self, id: usize) {
+        if id >= self.items.len() {
+            return;
+        }
+        self.focus = id;
+        // the list view is focused to highlight its focused item
+        self.content_mut()
+            .set_focus_index(1)
+            .unwrap_or(EventResult::Consumed(None));
+        if let Some(list) = self.get_list_view_mut() {
+            list.get_inner_mut()
+                .set_focus_index(id)
+                .unwrap_or(EventResult::Consumed(None));
+            list.scroll_to_important_area();
+        }
+    }
+
+    /// runs the focused item.
+    ///
+    /// If the focused item is a command with an unspecified argument,
+    /// the command is completed in the palette's query instead.
+    pub fn run_focused_item(&mut self, client: &'static client::HNClient) -> EventResult {
+        match self.items.get(self.focus) {
+            None => EventResult::Consumed(None),
+            Some(PaletteItem::Command(i)) => {
+                let event = self.commands[*i].get_event().cloned();
+                EventResult::with_cb(move |s| {
+                    s.pop_layer();
+                    // trigger the command's event as if it was sent by the user
+                    if let Some(event) = event.clone() {
+                        s.on_event(event);
+                    }
+                })
+            }
+            Some(PaletteItem::ArgumentCommand(command, Some(arg))) => {
+                let (command, arg) = (*command, arg.clone());
+                EventResult::with_cb(move |s| {
+                    s.pop_layer();
+                    command.run(s, client, &arg);
+                })
+            }
+            Some(PaletteItem::ArgumentCommand(command, None)) => {
+                let line = format!("{} ", command.name());
+                if let Some(view) = self.get_query_view_mut() {
+                    view.set_text(&line);
+                }
+                self.update_items();
+                EventResult::Consumed(None)
+            }
+        }
+    }
+}
+
+impl ViewWrapper for CommandPalette {
+    wrap_impl!(self.view: Dialog);
+}
+
+/// Match a query against a text in a fuzzy way, returning the match's score if matched.
+///
+/// The query matches the text if the query's characters (ignoring whitespaces)
+/// appear in the text in order. The comparison is case-insensitive.
+/// Consecutive matched characters and matches at the start of a word are scored higher.
+fn fuzzy_match(query: &str, text: &str) -> Option<usize> {
+    let text = text.to_lowercase().chars().collect::<Vec<_>>();
+
+    let (mut score, mut pos) = (0, 0);
+    let mut last_match: Option<usize> = None;
+    for c in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let i = pos + text[pos..].iter().position(|&t| t == c)?;
+        score += 1;
+        if last_match.is_some_and(|j| j + 1 == i) {
+            score += 4;
+        }
+        if i == 0 || !text[i - 1].is_alphanumeric() {
+            score += 2;
+        }
+        last_match = Some(i);
+        pos = i + 1;
+    }
+    Some(score)
+}
+
+/// Request a user's information and display it in a dialog once loaded
+pub(super) fn add_user_dialog(
+    s: &mut Cursive,
+    client: &'static client::HNClient,
+    username: String,
+) {
+    if !crate::utils::is_valid_username(&username) {
+        s.add_layer(utils::construct_info_dialog(
+            "Error",
+            format!("Invalid username: {username}"),
+        ));
+        return;
+    }
+
+    let cb_sink = s.cb_sink().clone();
+    std::thread::spawn(move || {
+        let user = client.get_user(&username);
+        let loaded = user.is_ok();
+        let content = match user {
+            Ok(user) => {
+                let component_style = &config::get_config_theme().component_style;
+                let mut text = StyledString::styled(&user.username, component_style.username);
+                text.append_plain(format!(
+                    "\n{} karma\njoined {} ago",
+                    user.karma,
+                    crate::utils::get_elapsed_time_as_text(user.created)
+                ));
+                if let Some(note) = storage::get_user_notes().get(&user.username) {
+                    text.append_styled(format!("\nnote: {note}"), component_style.metadata);
+                }
+                text
+            }
+            Err(err) => StyledString::plain(format!("Failed to get user {username}: {err:#}")),
+        };
+        let url = format!("{}/user?id={username}", client::HN_HOST_URL);
+
+        // the application may have been closed, ignore the sending errors
+        cb_sink
+            .send(Box::new(move |s| {
+                if loaded {
+                    storage::add_history_entry(
+                        storage::SessionView::UserView {
+                            username: username.clone(),
+                        },
+                        format!("User - {username}"),
+                    );
+                }
+                s.add_layer(
+                    OnEventView::new(
+                        Dialog::around(TextView::new(content.clone()))
+                            .title("User")
+                            .button("Open in browser", {
+                                let url = url.clone();
+                                move |_| utils::open_url_in_browser(&url)
+                            })
+                            .dismiss_button("Close"),
+                    )
+                    .on_pre_event(
+                        config::get_global_keymap().close_dialog.clone(),
+                        |s| {
+                            s.pop_layer();
+                        },
+                    ),
+                )
+            }))
+            .unwrap_or_default();
+    });
+}
+
+/// Construct a command palette listing the commands of a view
+pub fn construct_command_palette(
+    client: &'static client::HNClient,
+    groups: Vec<CommandGroup>,
+) -> impl View {
+    let command_palette_keymap = config::get_command_palette_keymap().clone();
+
+    OnEventView::new(CommandPalette::new(groups)).on_pre_event_inner(
+        EventTrigger::from_fn(|_| true),
+        move |s, e| {
+            let edit_keymap = config::get_edit_keymap();
+            match *e {
+                Event::Char(c) => {
+                    s.get_query_view_mut()?.add_char(c);
+                    s.update_items();
+                }
+                _ if command_palette_keymap.next_command.has_event(e) => {
+                    s.set_focus(s.focus + 1);
+                }
+                _ if command_palette_keymap.prev_command.has_event(e) => {
+                    s.set_focus(s.focus.saturating_sub(1));
+                }
+                _ if command_palette_keymap.run_command.has_event(e) => {
+                    return Some(s.run_focused_item(client));
+                }
+                _ if config::get_global_keymap().close_dialog.has_event(e) => {
+                    return Some(EventResult::with_cb(|s| {
+                        s.pop_layer();
+                    }));
+                }
+                _ => {
+                    let view = s.get_query_view_mut()?;
+                    if edit_keymap.backward_delete_char.has_event(e) {
+                        view.del_char();
+                        s.update_items();
+                    } else if edit_keymap.move_cursor_left.has_event(e) {
+                        view.move_cursor_left();
+                    } else if edit_keymap.move_cursor_right.has_event(e) {
+                        view.move_cursor_right();
+                    } else if edit_keymap.move_cursor_to_begin.has_event(e) {
+                        view.move_cursor_to_begin();
+                    } else if edit_keymap.move_cursor_to_end.has_event(e) {
+                        view.move_cursor_to_end();
+                    }
+                }
+            }
+            // the palette is a modal dialog, so other events are not handled by underlying views
+            Some(EventResult::Consumed(None))
+        },
+    )
+}
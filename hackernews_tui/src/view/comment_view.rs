@@ -1,16 +1,164 @@
-use super::{article_view, async_view, help_view::HasHelpView, text_view, traits::*, utils};
+use super::{
+    article_view, async_view, command_palette,
+    find_bar::{self, FindBar, FindBarContainer, OnFindEventView},
+    help_view::HasHelpView,
+    key_sequence, session, text_view,
+    traits::*,
+    utils,
+};
 use crate::prelude::*;
+use crate::storage;
 use crate::view::text_view::{StyledPaddingChar, TextPadding};
+use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
 
 type SingleItemView = HideableView<PaddedView<text_view::TextView>>;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// `CommentSortMode` defines how sibling comments are sorted in a comment view
+pub enum CommentSortMode {
+    /// the order returned by Hacker News
+    Default,
+    Newest,
+    Oldest,
+    /// comments with more descendants come first
+    MostReplies,
+}
+
+impl CommentSortMode {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Default => Self::Newest,
+            Self::Newest => Self::Oldest,
+            Self::Oldest => Self::MostReplies,
+            Self::MostReplies => Self::Default,
+        }
+    }
+
+    pub fn desc(self) -> &'static str {
+        match self {
+            Self::Default => "default",
+            Self::Newest => "newest",
+            Self::Oldest => "oldest",
+            Self::MostReplies => "most replies",
+        }
+    }
+
+    /// sorts sibling items in a list of items ordered in the tree's pre-order,
+    /// preserving the tree structure
+    fn sort<T>(self, items: Vec<(HnItem, T)>) -> Vec<(HnItem, T)> {
+        let Some(min_level) = items.iter().map(|(item, _)| item.level).min() else {
+            return items;
+        };
+        if self == Self::Default {
+            return items;
+        }
+
+        // split the items into subtrees, each of which is rooted at an item with the minimum level
+        let mut subtrees: Vec<Vec<(HnItem, T)>> = vec![];
+        for entry in items {
+            match subtrees.last_mut() {
+                Some(subtree) if entry.0.level > min_level => subtree.push(entry),
+                _ => subtrees.push(vec![entry]),
+            }
+        }
+
+        subtrees.sort_by(|x, y| {
+            let (x, y) = (&x[0].0, &y[0].0);
+            match self {
+                Self::Default => std::cmp::Ordering::Equal,
+                Self::Newest => y.time.cmp(&x.time),
+                Self::Oldest => x.time.cmp(&y.time),
+                Self::MostReplies => y.n_children.cmp(&x.n_children),
+            }
+        });
+
+        subtrees
+            .into_iter()
+            .flat_map(|mut subtree| {
+                let descendants = subtree.split_off(1);
+                subtree.extend(self.sort(descendants));
+                subtree
+            })
+            .collect()
+    }
+}
+
+/// the depth to collapse comments to if no count is given
+const DEFAULT_COLLAPSE_DEPTH: usize = 1;
+
+/// Get the depth to collapse comments to from a key sequence's count.
+///
+/// Comments nested deeper than the depth are hidden, top-level comments are at depth `0`.
+fn get_collapse_depth(count: Option<usize>) -> usize {
+    count.unwrap_or(DEFAULT_COLLAPSE_DEPTH)
+}
+
+/// Get the ids of comments at the given level from the levels of a comment view's items.
+///
+/// The first item is the story, which is never collapsed.
+fn get_comment_ids_at_level(levels: &[usize], level: usize) -> Vec<usize> {
+    (1..levels.len())
+        .filter(|&id| levels[id] == level)
+        .collect()
+}
+
+/// An update of the information about a user displayed in a comment view
+enum UserUpdate {
+    /// the user's metadata is loaded
+    Metadata(User),
+    /// the note of the user with the given username is changed
+    Note(String),
+}
+
 /// CommentView is a View displaying a list of comments in a HN story
 pub struct CommentView {
     view: ScrollView<LinearLayout>,
     items: Vec<HnItem>,
     data: PageData,
 
-    raw_command: String,
+    /// id of a HN item to focus once it's loaded
+    pending_focus_item_id: Option<u32>,
+    /// whether to scroll to the focused item in the next layout
+    scroll_on_layout: bool,
+
+    /// ids of collapsed items, whose collapse state is restored once they're (re)loaded
+    collapsed_item_ids: HashSet<u32>,
+    /// whether the state of the thread is restored from and saved to the local storage
+    persist_state: bool,
+    /// a channel to receive the refreshed page data
+    refresh_receiver: Option<std::sync::mpsc::Receiver<Result<PageData>>>,
+
+    /// the position of each item in the order returned by Hacker News
+    default_order: HashMap<u32, usize>,
+    sort_mode: CommentSortMode,
+    /// a query to filter comments by their author or text
+    filter_query: String,
+    /// items (and their views) excluded by the comment filter
+    filtered_items: Vec<(HnItem, Box<dyn View>)>,
+    /// a channel to receive a new comment filter query
+    filter_sender: std::sync::mpsc::Sender<String>,
+    filter_receiver: std::sync::mpsc::Receiver<String>,
+
+    find_bar: FindBar,
+    /// the id of the item containing each match of the find query
+    find_match_item_ids: Vec<usize>,
+    /// the id of the item containing the focused match
+    focused_match_item_id: Option<usize>,
+
+    /// whether to show the metadata (karma, account age) of comments' authors
+    show_user_metadata: bool,
+    /// usernames of users whose metadata is requested
+    requested_users: HashSet<String>,
+    users: HashMap<String, User>,
+    /// local notes of users, keyed by the users' usernames
+    user_notes: HashMap<String, String>,
+    /// a channel to receive updates of the users' information
+    user_sender: std::sync::mpsc::Sender<UserUpdate>,
+    user_receiver: std::sync::mpsc::Receiver<UserUpdate>,
+
+    client: &'static client::HNClient,
+    cb_sink: CbSink,
 }
 
 pub enum NavigationDirection {
@@ -19,11 +167,78 @@ pub enum NavigationDirection {
 }
 
 impl ViewWrapper for CommentView {
-    wrap_impl!(self.view: ScrollView<LinearLayout>);
+    // `wrap_impl!` is not used as it moves the inner view out of `CommentView`,
+    // which implements `Drop`
+    type V = ScrollView<LinearLayout>;
+
+    fn with_view<F, R>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&Self::V) -> R,
+    {
+        Some(f(&self.view))
+    }
+
+    fn with_view_mut<F, R>(&mut self, f: F) -> Option<R>
+    where
+        F: FnOnce(&mut Self::V) -> R,
+    {
+        Some(f(&mut self.view))
+    }
+
+    fn wrap_layout(&mut self, size: Vec2) {
+        self.try_refresh();
+        if let Ok(query) = self.filter_receiver.try_recv() {
+            self.set_filter_query(query);
+        }
+        self.try_update_users();
+        self.view
+            .layout(size.saturating_sub((0, self.find_bar.height())));
+        self.find_bar.layout(size);
+
+        if self.scroll_on_layout {
+            self.scroll_on_layout = false;
+            self.view.scroll_to_important_area();
+        }
+    }
+
+    fn wrap_draw(&self, printer: &Printer) {
+        let height = self.find_bar.height();
+        self.view
+            .draw(&printer.cropped(printer.size.saturating_sub((0, height))));
+        self.find_bar.draw(printer);
+    }
+
+    fn wrap_required_size(&mut self, req: Vec2) -> Vec2 {
+        let height = self.find_bar.height();
+        self.view.required_size(req.saturating_sub((0, height))) + (0, height)
+    }
 }
 
 impl CommentView {
-    pub fn new(data: PageData) -> Self {
+    /// constructs a new `CommentView`.
+    ///
+    /// If `focus_item_id` is specified, the view will focus the corresponding item once it's loaded.
+    /// If `persist_state` is `false`, the state of the thread is neither restored nor saved.
+    pub fn new(
+        client: &'static client::HNClient,
+        cb_sink: CbSink,
+        data: PageData,
+        focus_item_id: Option<u32>,
+        persist_state: bool,
+    ) -> Self {
+        // restore the state of the thread from the last time it's viewed
+        let thread_state = if persist_state {
+            storage::get_thread_state(data.root_item.id).unwrap_or_default()
+        } else {
+            storage::ThreadState::default()
+        };
+
+        let (filter_sender, filter_receiver) = std::sync::mpsc::channel();
+        let (user_sender, user_receiver) = std::sync::mpsc::channel();
+        let root_vote_status = data
+            .vote_state
+            .get(&data.root_item.id.to_string())
+            .map(|v| v.upvoted);
         let mut view = CommentView {
             view: LinearLayout::vertical()
                 .child(HideableView::new(PaddedView::lrtb(
@@ -31,17 +246,35 @@ impl CommentView {
                     1,
                     0,
                     1,
-                    text_view::TextView::new(
-                        data.root_item.text(
-                            data.vote_state
-                                .get(&data.root_item.id.to_string())
-                                .map(|v| v.upvoted),
-                        ),
+                    text_view::TextView::new(data.root_item.text(root_vote_status)).links(
+                        data.root_item.links.clone(),
+                        data.root_item.link_markers(root_vote_status),
                     ),
                 )))
                 .scrollable(),
             items: vec![data.root_item.clone()],
-            raw_command: String::new(),
+            pending_focus_item_id: focus_item_id.or(thread_state.focused_item_id),
+            scroll_on_layout: false,
+            collapsed_item_ids: thread_state.collapsed_item_ids,
+            persist_state,
+            refresh_receiver: None,
+            default_order: HashMap::new(),
+            sort_mode: CommentSortMode::Default,
+            filter_query: String::new(),
+            filtered_items: vec![],
+            filter_sender,
+            filter_receiver,
+            find_bar: FindBar::new(),
+            find_match_item_ids: vec![],
+            focused_match_item_id: None,
+            show_user_metadata: config::get_config().show_user_metadata,
+            requested_users: HashSet::new(),
+            users: HashMap::new(),
+            user_notes: storage::get_user_notes(),
+            user_sender,
+            user_receiver,
+            client,
+            cb_sink,
             data,
         };
 
@@ -66,41 +299,507 @@ impl CommentView {
             return;
         }
 
+        // collapse comments by users matching the filtering rules
+        let filter = &config::get_config().filter;
+        self.collapsed_item_ids.extend(
+            new_comments
+                .iter()
+                .filter(|comment| filter.match_user(&comment.author))
+                .map(|comment| comment.id),
+        );
+
+        let root_author = self.data.root_item.author.clone();
         let mut new_items = new_comments
             .into_iter()
-            .map(Into::<HnItem>::into)
+            .map(|comment| HnItem::from_comment(comment, Some(&root_author)))
             .collect::<Vec<_>>();
+        for item in new_items.iter_mut() {
+            item.user_annotation = self.get_user_annotation(&item.author);
+        }
+        let start_id = self.items.len();
 
         new_items.iter().for_each(|item| {
-            let text_view = text_view::TextView::new(item.text(self.get_vote_status(item.id)));
+            let vote_status = self.get_vote_status(item.id);
+            let text_view = text_view::TextView::new(item.text(vote_status))
+                .links(item.links.clone(), item.link_markers(vote_status));
+            let (margin, padding) = get_item_padding(item.level);
             self.add_item(HideableView::new(PaddedView::lrtb(
-                item.level * 2 + 1,
+                margin,
                 1,
                 0,
                 1,
-                if item.level > 0 {
-                    // get the padding style (color) based on the comment's height
-                    //
-                    // We use base 16 colors to display the comment's padding
-                    let c = config::Color::from((item.level % 16) as u8);
-                    text_view
-                        .padding(TextPadding::default().left(StyledPaddingChar::new('▎', c.into())))
-                } else {
-                    // add top padding for top comments, use the first color in the 16 base colors
-                    let c = config::Color::from(0);
-                    text_view
-                        .padding(TextPadding::default().top(StyledPaddingChar::new('▔', c.into())))
-                },
+                text_view.padding(padding),
             )));
         });
+        let n_loaded_items = self.default_order.len();
+        self.default_order.extend(
+            new_items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| (item.id, n_loaded_items + i)),
+        );
         self.items.append(&mut new_items);
+        if self.sort_mode != CommentSortMode::Default {
+            self.restore_collapse_state(start_id);
+            self.rebuild_items();
+        } else {
+            // new comments are appended in the default order,
+            // so only the new comments need to be filtered
+            if !self.filter_query.is_empty() {
+                self.filter_new_items(start_id);
+            }
+            self.restore_collapse_state(start_id);
+            self.update_find_matches();
+        }
 
         // update the view's layout
         self.layout(
             self.get_inner_scroll_view()
                 .get_scroller()
                 .last_outer_size(),
-        )
+        );
+
+        self.try_focus_pending_item();
+        self.request_users_metadata();
+    }
+
+    /// Request in the background the metadata of comments' authors
+    /// if the user metadata is shown
+    fn request_users_metadata(&mut self) {
+        if !self.show_user_metadata {
+            return;
+        }
+
+        let usernames = self
+            .items
+            .iter()
+            .map(|item| &item.author)
+            .filter(|author| !author.is_empty() && !self.requested_users.contains(*author))
+            .cloned()
+            .collect::<HashSet<_>>();
+        if usernames.is_empty() {
+            return;
+        }
+        self.requested_users.extend(usernames.iter().cloned());
+
+        let client = self.client;
+        let sender = self.user_sender.clone();
+        let cb_sink = self.cb_sink.clone();
+        std::thread::spawn(move || {
+            usernames
+                .into_par_iter()
+                .for_each(|username| match client.get_user(&username) {
+                    Ok(user) => {
+                        // the comment view may have been destroyed, ignore the sending errors
+                        sender.send(UserUpdate::Metadata(user)).unwrap_or_default();
+                        // send a dummy callback to trigger a re-render of the comment view
+                        cb_sink.send(Box::new(move |_| {})).unwrap_or_default();
+                    }
+                    Err(err) => warn!("failed to get user (username={username}): {err:#}"),
+                });
+        });
+    }
+
+    /// Check the user receiver channel if there are updates of the users' information
+    /// then update the corresponding items accordingly.
+    fn try_update_users(&mut self) {
+        while let Ok(update) = self.user_receiver.try_recv() {
+            let username = match update {
+                UserUpdate::Metadata(user) => {
+                    let username = user.username.clone();
+                    self.users.insert(username.clone(), user);
+                    username
+                }
+                UserUpdate::Note(username) => {
+                    self.user_notes = storage::get_user_notes();
+                    username
+                }
+            };
+            self.update_user_annotations(Some(&username));
+        }
+    }
+
+    /// Toggle showing the metadata of comments' authors
+    pub fn toggle_user_metadata(&mut self) {
+        self.show_user_metadata = !self.show_user_metadata;
+        self.request_users_metadata();
+        self.update_user_annotations(None);
+    }
+
+    /// Update the user annotation of items posted by the given user or by any users if not specified
+    fn update_user_annotations(&mut self, username: Option<&str>) {
+        for id in 0..self.len() {
+            if username.is_some_and(|username| username != self.items[id].author) {
+                continue;
+            }
+            self.items[id].user_annotation = self.get_user_annotation(&self.items[id].author);
+            self.update_item_text_content(id);
+        }
+    }
+
+    /// Get the annotation rendered next to a user's username, which consists of
+    /// the user's metadata (if shown) and the user's local note
+    fn get_user_annotation(&self, username: &str) -> Option<StyledString> {
+        let component_style = &config::get_config_theme().component_style;
+        let mut annotation = StyledString::new();
+
+        if self.show_user_metadata {
+            if let Some(user) = self.users.get(username) {
+                annotation.append_styled(
+                    format!(
+                        " ({} karma, joined {} ago)",
+                        user.karma,
+                        crate::utils::get_elapsed_time_as_text(user.created)
+                    ),
+                    component_style.metadata,
+                );
+            }
+        }
+        if let Some(note) = self.user_notes.get(username) {
+            annotation.append_styled(format!(" [{note}]"), component_style.user_note);
+        }
+
+        if annotation.is_empty() {
+            None
+        } else {
+            Some(annotation)
+        }
+    }
+
+    /// Get the ids of collapsed items, including collapsed items that are not loaded yet
+    fn get_collapsed_item_ids(&self) -> HashSet<u32> {
+        let loaded_items = self
+            .items
+            .iter()
+            .chain(self.filtered_items.iter().map(|(item, _)| item));
+
+        let mut ids = self.collapsed_item_ids.clone();
+        for item in loaded_items {
+            if matches!(item.display_state, DisplayState::Minimized) {
+                ids.insert(item.id);
+            } else {
+                ids.remove(&item.id);
+            }
+        }
+        ids
+    }
+
+    /// Save the collapse state of items and the focused item, which are restored
+    /// when the comment view of the same item is constructed again
+    fn save_thread_state(&self) {
+        let focus_id = self.get_focus_index();
+        storage::save_thread_state(
+            self.data.root_item.id,
+            storage::ThreadState {
+                collapsed_item_ids: self.get_collapsed_item_ids(),
+                focused_item_id: if focus_id > 0 {
+                    Some(self.items[focus_id].id)
+                } else {
+                    self.pending_focus_item_id
+                },
+                time: crate::utils::get_current_unix_time(),
+            },
+        );
+    }
+
+    /// Restore the collapse state of items starting from the `start_id`-th item
+    /// based on the ids of collapsed items.
+    fn restore_collapse_state(&mut self, start_id: usize) {
+        if self.collapsed_item_ids.is_empty() {
+            return;
+        }
+
+        for id in start_id..self.len() {
+            if self.collapsed_item_ids.contains(&self.items[id].id)
+                && !matches!(self.items[id].display_state, DisplayState::Minimized)
+            {
+                self.items[id].display_state = DisplayState::Minimized;
+                self.update_item_text_content(id);
+            }
+        }
+        self.update_items_visibility(start_id);
+    }
+
+    /// Update the visibility of items starting from the `start_id`-th item
+    /// based on the collapse state of their parents.
+    fn update_items_visibility(&mut self, start_id: usize) {
+        for id in start_id..self.len() {
+            let level = self.items[id].level;
+            // an item is visible if its parent is visible and not collapsed
+            let visible = level == 0 || {
+                let parent_id =
+                    self.find_item_id_by_max_level(id, level - 1, NavigationDirection::Previous);
+                matches!(self.items[parent_id].display_state, DisplayState::Normal)
+                    && self.get_item_view(parent_id).is_visible()
+            };
+
+            match self.items[id].display_state {
+                DisplayState::Minimized => {}
+                DisplayState::Normal | DisplayState::Hidden => {
+                    self.items[id].display_state = if visible {
+                        DisplayState::Normal
+                    } else {
+                        DisplayState::Hidden
+                    };
+                }
+            }
+            self.get_item_view_mut(id).set_visible(visible);
+        }
+    }
+
+    /// Cycle the sort mode of comments
+    pub fn cycle_sort_mode(&mut self) {
+        self.sort_mode = self.sort_mode.next();
+        self.rebuild_items();
+    }
+
+    /// Set the query to filter comments. An empty query clears the filter.
+    pub fn set_filter_query(&mut self, query: String) {
+        self.filter_query = query.trim().to_string();
+        self.rebuild_items();
+    }
+
+    /// Rebuild the list of items based on the current sort mode and filter query,
+    /// preserving the items' nesting, their collapse state and the focused item.
+    fn rebuild_items(&mut self) {
+        let focused_item_id = self.items[self.get_focus_index()].id;
+
+        // take all the items (except the root item) and their views out of the list
+        self.set_focus_index(0);
+        let mut views = (1..self.len())
+            .rev()
+            .filter_map(|id| self.get_inner_list_mut().remove_child(id))
+            .collect::<Vec<_>>();
+        views.reverse();
+        let mut entries = std::mem::take(&mut self.filtered_items);
+        entries.extend(self.items.split_off(1).into_iter().zip(views));
+
+        entries.sort_by_key(|(item, _)| {
+            self.default_order
+                .get(&item.id)
+                .copied()
+                .unwrap_or(usize::MAX)
+        });
+        let entries = self.sort_mode.sort(entries);
+
+        let kept = match_filter_query(
+            &self.filter_query,
+            &entries.iter().map(|(item, _)| item).collect::<Vec<_>>(),
+        );
+        for ((item, view), kept) in entries.into_iter().zip(kept) {
+            if kept {
+                self.items.push(item);
+                self.get_inner_list_mut().add_child(view);
+            } else {
+                self.filtered_items.push((item, view));
+            }
+        }
+        self.update_items_visibility(1);
+        self.update_item_text_content(0);
+
+        let focus_id = self
+            .items
+            .iter()
+            .position(|item| item.id == focused_item_id)
+            .unwrap_or_default();
+        self.set_focus_index(focus_id);
+        self.scroll_on_layout = true;
+
+        self.update_find_matches();
+    }
+
+    /// Filter the new items starting from the `start_id`-th item based on the filter query,
+    /// moving the items that don't match the query out of the list
+    fn filter_new_items(&mut self, start_id: usize) {
+        let kept = match_filter_query(
+            &self.filter_query,
+            &self.items[start_id..].iter().collect::<Vec<_>>(),
+        );
+
+        let new_items = self.items.split_off(start_id);
+        let views = (start_id..self.len())
+            .rev()
+            .filter_map(|id| self.get_inner_list_mut().remove_child(id))
+            .collect::<Vec<_>>();
+        for ((item, view), kept) in new_items.into_iter().zip(views.into_iter().rev()).zip(kept) {
+            if kept {
+                self.items.push(item);
+                self.get_inner_list_mut().add_child(view);
+            } else {
+                self.filtered_items.push((item, view));
+            }
+        }
+        self.update_item_text_content(0);
+    }
+
+    /// Update the matches of the find query after the list of items is changed
+    fn update_find_matches(&mut self) {
+        let query = self.find_bar.query();
+        if query.is_empty() {
+            return;
+        }
+        let (n_matches, _) = self.find_matches(&query);
+        let current_match = self.find_bar.current_match();
+        self.find_bar.set_matches(n_matches, current_match);
+    }
+
+    /// Expand collapsed items containing matches of the find query, including their ancestors
+    fn expand_matched_items(&mut self) {
+        let mut expanded = false;
+        let mut last_id = None;
+        for i in 0..self.find_match_item_ids.len() {
+            let mut id = self.find_match_item_ids[i];
+            if last_id == Some(id) {
+                continue;
+            }
+            last_id = Some(id);
+
+            loop {
+                if matches!(self.items[id].display_state, DisplayState::Minimized) {
+                    self.items[id].display_state = DisplayState::Normal;
+                    expanded = true;
+                }
+                let level = self.items[id].level;
+                if level == 0 {
+                    break;
+                }
+                id = self.find_item_id_by_max_level(id, level - 1, NavigationDirection::Previous);
+            }
+        }
+        if expanded {
+            self.update_items_visibility(1);
+        }
+    }
+
+    /// Get the text describing the current sort mode and filter of comments
+    fn get_sort_and_filter_desc(&self) -> Option<StyledString> {
+        let mut desc = vec![];
+        if self.sort_mode != CommentSortMode::Default {
+            desc.push(format!("sorted by: {}", self.sort_mode.desc()));
+        }
+        if !self.filter_query.is_empty() {
+            desc.push(format!(
+                "filter: \"{}\" ({} hidden)",
+                self.filter_query,
+                self.filtered_items.len()
+            ));
+        }
+        if desc.is_empty() {
+            None
+        } else {
+            Some(StyledString::styled(
+                format!("\n[{}]", desc.join(" | ")),
+                config::get_config_theme().component_style.metadata,
+            ))
+        }
+    }
+
+    /// Re-fetch the page data in the background to refresh the view
+    pub fn refresh(&mut self) {
+        if self.refresh_receiver.is_some() {
+            // the view is being refreshed
+            return;
+        }
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        self.refresh_receiver = Some(receiver);
+
+        let item_id = self.data.root_item.id;
+        let client = self.client;
+        let cb_sink = self.cb_sink.clone();
+        std::thread::spawn(move || {
+            // the view may have been destroyed, ignore the sending errors
+            if sender.send(client.get_page_data(item_id)).is_ok() {
+                // send a dummy callback to `cb_sink` to re-draw the application
+                cb_sink.send(Box::new(move |_| {})).unwrap_or_default();
+            }
+        });
+    }
+
+    /// Check if the refreshed page data is received then rebuild the view with the new data,
+    /// preserving the collapse state of items and the focused item.
+    fn try_refresh(&mut self) {
+        let data = match self.refresh_receiver.as_ref().map(|r| r.try_recv()) {
+            Some(Ok(data)) => data,
+            Some(Err(std::sync::mpsc::TryRecvError::Empty)) | None => return,
+            Some(Err(std::sync::mpsc::TryRecvError::Disconnected)) => {
+                self.refresh_receiver = None;
+                return;
+            }
+        };
+        self.refresh_receiver = None;
+
+        let data = match data {
+            Ok(data) => data,
+            Err(err) => {
+                warn!(
+                    "failed to refresh comments of item (id={}): {err}",
+                    self.data.root_item.id
+                );
+                return;
+            }
+        };
+
+        let focused_item_id = self.items[self.get_focus_index()].id;
+        self.collapsed_item_ids = self.get_collapsed_item_ids();
+
+        // remove all items except the root item, which is updated with the new data
+        self.set_focus_index(0);
+        for id in (1..self.len()).rev() {
+            self.get_inner_list_mut().remove_child(id);
+        }
+        self.items.truncate(1);
+        self.filtered_items.clear();
+        self.default_order.clear();
+
+        let mut root_item = data.root_item.clone();
+        root_item.display_state = self.items[0].display_state.clone();
+        self.items[0] = root_item;
+        self.data = data;
+        self.update_item_text_content(0);
+
+        if focused_item_id != self.data.root_item.id {
+            self.pending_focus_item_id = Some(focused_item_id);
+        }
+        self.try_update_comments();
+    }
+
+    /// Focus the pending item if it has been loaded
+    fn try_focus_pending_item(&mut self) {
+        let Some(item_id) = self.pending_focus_item_id else {
+            return;
+        };
+        if let Some(id) = self.items.iter().position(|item| item.id == item_id) {
+            self.pending_focus_item_id = None;
+            // the item may be hidden under collapsed ancestors, which need to be expanded
+            // as a hidden item cannot be focused
+            self.expand_item_ancestors(id);
+            self.set_focus_index(id);
+            self.focus_visible_item();
+            // the view may not have been laid out yet, so defer the scrolling to the next layout
+            self.scroll_on_layout = true;
+        }
+    }
+
+    /// Expand the collapsed ancestors of the `id`-th item, making the item visible
+    fn expand_item_ancestors(&mut self, id: usize) {
+        let mut ancestor_ids = vec![];
+        let mut id = id;
+        while self.items[id].level > 0 {
+            id = self.find_item_id_by_max_level(
+                id,
+                self.items[id].level - 1,
+                NavigationDirection::Previous,
+            );
+            ancestor_ids.push(id);
+        }
+
+        // expand the ancestors from the outermost one, so each ancestor is visible when expanded
+        for id in ancestor_ids.into_iter().rev() {
+            if matches!(self.items[id].display_state, DisplayState::Minimized) {
+                self.toggle_item_collapse_state(id);
+            }
+        }
     }
 
     /// Return the id of the first item (`direction` dependent),
@@ -121,6 +820,24 @@ impl CommentView {
         }
     }
 
+    /// Return the id of the next visible comment posted by the story's author (`direction` dependent)
+    pub fn find_next_visible_op_item(
+        &self,
+        start_id: usize,
+        direction: NavigationDirection,
+    ) -> usize {
+        let is_visible_op_item =
+            |id: &usize| self.items[*id].is_op && self.get_item_view(*id).is_visible();
+        match direction {
+            NavigationDirection::Next => (start_id + 1..self.len())
+                .find(is_visible_op_item)
+                .unwrap_or(start_id),
+            NavigationDirection::Previous => {
+                (0..start_id).rfind(is_visible_op_item).unwrap_or(start_id)
+            }
+        }
+    }
+
     /// Return the id of the next visible item (`direction` dependent)
     pub fn find_next_visible_item(&self, start_id: usize, direction: NavigationDirection) -> usize {
         match direction {
@@ -196,11 +913,15 @@ impl CommentView {
 
     /// Toggle the collapsing state of currently focused item and its children
     pub fn toggle_collapse_focused_item(&mut self) {
-        let id = self.get_focus_index();
+        self.toggle_item_collapse_state(self.get_focus_index());
+    }
+
+    /// Toggle the collapsing state of the `id`-th item and its children
+    fn toggle_item_collapse_state(&mut self, id: usize) {
         match self.items[id].display_state {
             DisplayState::Hidden => {
                 panic!(
-                    "invalid collapse state `Collapsed` when calling `toggle_collapse_focused_item`"
+                    "invalid collapse state `Collapsed` when calling `toggle_item_collapse_state`"
                 );
             }
             DisplayState::Minimized => {
@@ -215,13 +936,67 @@ impl CommentView {
         self.update_item_text_content(id);
     }
 
+    /// Collapse all items at a given level, hiding all items nested deeper than that level
+    pub fn collapse_items_at_level(&mut self, level: usize) {
+        let levels = self.items.iter().map(|item| item.level).collect::<Vec<_>>();
+        for id in get_comment_ids_at_level(&levels, level) {
+            match self.items[id].display_state {
+                DisplayState::Normal => self.toggle_item_collapse_state(id),
+                DisplayState::Hidden => {
+                    // the item's children are already hidden, only its state needs to be updated
+                    self.items[id].display_state = DisplayState::Minimized;
+                    self.update_item_text_content(id);
+                }
+                DisplayState::Minimized => {}
+            }
+        }
+        self.focus_visible_item();
+    }
+
+    /// Expand all collapsed items
+    pub fn expand_all_items(&mut self) {
+        // Items are iterated in the tree's pre-order, so a collapsed item's ancestors are
+        // already expanded when the item is visited, making the item visible.
+        for id in 0..self.len() {
+            if matches!(self.items[id].display_state, DisplayState::Minimized) {
+                self.toggle_item_collapse_state(id);
+            }
+        }
+    }
+
+    /// Move the focus to the nearest visible item if the focused item is hidden
+    fn focus_visible_item(&mut self) {
+        let id = self.get_focus_index();
+        if !self.get_item_view(id).is_visible() {
+            // the nearest visible item before a hidden item is its collapsed ancestor
+            let next_id = self.find_next_visible_item(id, NavigationDirection::Previous);
+            self.set_focus_index(next_id);
+        }
+    }
+
     /// Update the `id`-th item's text content based on its state-based text
     pub fn update_item_text_content(&mut self, id: usize) {
-        let new_content = self.items[id].text(self.get_vote_status(self.items[id].id));
-        self.get_item_view_mut(id)
-            .get_inner_mut()
-            .get_inner_mut()
-            .set_content(new_content);
+        let vote_status = self.get_vote_status(self.items[id].id);
+        let mut new_content = self.items[id].text(vote_status);
+        let link_markers = self.items[id].link_markers(vote_status);
+        if id == 0 {
+            if let Some(desc) = self.get_sort_and_filter_desc() {
+                new_content.append(desc);
+            }
+        }
+
+        let query = self.find_bar.query();
+        if !query.is_empty() {
+            // the focused match's index relative to the item's matches
+            let current_match = self.find_bar.current_match().and_then(|i| {
+                let first_match = self.find_match_item_ids.iter().position(|&x| x == id)?;
+                (self.find_match_item_ids.get(i) == Some(&id)).then(|| i - first_match)
+            });
+            new_content = find_bar::highlight_matches(new_content, &query, current_match).content;
+        }
+        let text_view = self.get_item_view_mut(id).get_inner_mut().get_inner_mut();
+        text_view.set_content(new_content);
+        text_view.set_link_markers(link_markers);
     }
 
     inner_getters!(self.view: ScrollView<LinearLayout>);
@@ -244,6 +1019,62 @@ impl ListViewContainer for CommentView {
     }
 }
 
+impl Drop for CommentView {
+    fn drop(&mut self) {
+        if self.persist_state {
+            self.save_thread_state();
+        }
+    }
+}
+
+impl FindBarContainer for CommentView {
+    fn get_find_bar(&self) -> &FindBar {
+        &self.find_bar
+    }
+
+    fn get_find_bar_mut(&mut self) -> &mut FindBar {
+        &mut self.find_bar
+    }
+
+    fn find_matches(&mut self, query: &str) -> (usize, Option<usize>) {
+        self.find_match_item_ids.clear();
+        for id in 0..self.len() {
+            let n_matches = find_bar::count_matches(&self.items[id].plain_text(), query);
+            self.find_match_item_ids
+                .extend(std::iter::repeat(id).take(n_matches));
+        }
+        self.expand_matched_items();
+        for id in 0..self.len() {
+            self.update_item_text_content(id);
+        }
+
+        // focus the first match starting from the focused item
+        let focus_id = self.get_focus_index();
+        let current_match = self
+            .find_match_item_ids
+            .iter()
+            .position(|&id| id >= focus_id)
+            .or_else(|| (!self.find_match_item_ids.is_empty()).then_some(0));
+        (self.find_match_item_ids.len(), current_match)
+    }
+
+    fn focus_match(&mut self, i: Option<usize>) {
+        if let Some(id) = self.focused_match_item_id.take() {
+            if id < self.len() {
+                self.update_item_text_content(id);
+            }
+        }
+
+        let Some(&id) = i.and_then(|i| self.find_match_item_ids.get(i)) else {
+            return;
+        };
+        self.focused_match_item_id = Some(id);
+        self.update_item_text_content(id);
+        self.set_focus_index(id);
+        self.scroll_on_layout = true;
+    }
+}
+
 impl ScrollViewContainer for CommentView {
     type ScrollInner = LinearLayout;
 
@@ -256,33 +1087,71 @@ impl ScrollViewContainer for CommentView {
     }
 }
 
-fn construct_comment_main_view(client: &'static client::HNClient, data: PageData) -> impl View {
-    let is_suffix_key = |c: &Event| -> bool {
-        let comment_view_keymap = config::get_comment_view_keymap();
-        comment_view_keymap.open_link_in_browser.has_event(c)
-            || comment_view_keymap.open_link_in_article_view.has_event(c)
-    };
+/// Match items against a filter query, returning whether each item is kept.
+///
+/// The query consists of an optional `author:<username>` operator and a text matched against
+/// the items' content. An item is kept if it or one of its descendants matches the query.
+fn match_filter_query(query: &str, items: &[&HnItem]) -> Vec<bool> {
+    if query.is_empty() {
+        return vec![true; items.len()];
+    }
+
+    let mut author = None;
+    let mut text = vec![];
+    for word in query.split_whitespace() {
+        match word.strip_prefix("author:") {
+            Some(username) if !username.is_empty() => author = Some(username),
+            _ => text.push(word),
+        }
+    }
+    let text = text.join(" ").to_lowercase();
+
+    let mut kept = vec![false; items.len()];
+    for (i, item) in items.iter().enumerate() {
+        let item_text = item.plain_text();
+        let content = item_text
+            .split_once('\n')
+            .map(|(_, content)| content)
+            .unwrap_or_default();
+        let matched = author.map_or(true, |author| author == item.author)
+            && content.to_lowercase().contains(&text);
+        if !matched {
+            continue;
+        }
+
+        kept[i] = true;
+        // keep the item's ancestors
+        let mut level = item.level;
+        for j in (0..i).rev() {
+            if level == 0 {
+                break;
+            }
+            if items[j].level < level {
+                kept[j] = true;
+                level = items[j].level;
+            }
+        }
+    }
+    kept
+}
 
+fn construct_comment_main_view(
+    client: &'static client::HNClient,
+    data: PageData,
+    focus_item_id: Option<u32>,
+    cb_sink: CbSink,
+    persist_state: bool,
+) -> impl View {
     let comment_view_keymap = config::get_comment_view_keymap().clone();
 
     let article_url = data.url.clone();
     let page_url = format!("{}/item?id={}", client::HN_HOST_URL, data.root_item.id);
 
-    OnEventView::new(CommentView::new(data))
-        .on_pre_event_inner(EventTrigger::from_fn(|_| true), move |s, e| {
+    let comment_view = CommentView::new(client, cb_sink, data, focus_item_id, persist_state);
+    let view = OnEventView::new(comment_view)
+        .on_pre_event_inner(EventTrigger::from_fn(|_| true), |s, _| {
             s.try_update_comments();
 
-            match *e {
-                Event::Char(c) if c.is_ascii_digit() => {
-                    s.raw_command.push(c);
-                }
-                _ => {
-                    if !is_suffix_key(e) {
-                        s.raw_command.clear();
-                    }
-                }
-            };
-
             // don't allow the inner `LinearLayout` child view to handle the event
             // because of its pre-defined `on_event` function
             Some(EventResult::Ignored)
@@ -357,28 +1226,30 @@ fn construct_comment_main_view(client: &'static client::HNClient, data: PageData
                 Some(EventResult::Consumed(None))
             }
         })
+        .on_pre_event_inner(comment_view_keymap.next_op_comment, |s, _| {
+            let next_id =
+                s.find_next_visible_op_item(s.get_focus_index(), NavigationDirection::Next);
+            s.set_focus_index(next_id)
+        })
+        .on_pre_event_inner(comment_view_keymap.prev_op_comment, |s, _| {
+            let next_id =
+                s.find_next_visible_op_item(s.get_focus_index(), NavigationDirection::Previous);
+            s.set_focus_index(next_id)
+        })
         // open external link shortcuts
         .on_pre_event_inner(comment_view_keymap.open_link_in_browser, |s, _| {
-            match s.raw_command.parse::<usize>() {
-                Ok(num) => {
-                    s.raw_command.clear();
-                    utils::open_ith_link_in_browser(&s.items[s.get_focus_index()].links, num)
-                }
-                Err(_) => None,
-            }
+            let num = key_sequence::get_count()?;
+            utils::open_ith_link_in_browser(&s.items[s.get_focus_index()].links, num)
         })
         .on_pre_event_inner(
             comment_view_keymap.open_link_in_article_view,
-            move |s, _| match s.raw_command.parse::<usize>() {
-                Ok(num) => {
-                    s.raw_command.clear();
-                    utils::open_ith_link_in_article_view(
-                        client,
-                        &s.items[s.get_focus_index()].links,
-                        num,
-                    )
-                }
-                Err(_) => None,
+            move |s, _| {
+                let num = key_sequence::get_count()?;
+                utils::open_ith_link_in_article_view(
+                    client,
+                    &s.items[s.get_focus_index()].links,
+                    num,
+                )
             },
         )
         .on_pre_event_inner(comment_view_keymap.open_comment_in_browser, move |s, _| {
@@ -392,6 +1263,52 @@ fn construct_comment_main_view(client: &'static client::HNClient, data: PageData
             s.toggle_collapse_focused_item();
             Some(EventResult::Consumed(None))
         })
+        .on_pre_event_inner(comment_view_keymap.collapse_all_comments, |s, _| {
+            s.collapse_items_at_level(0);
+            Some(EventResult::Consumed(None))
+        })
+        .on_pre_event_inner(comment_view_keymap.expand_all_comments, |s, _| {
+            s.expand_all_items();
+            Some(EventResult::Consumed(None))
+        })
+        .on_pre_event_inner(comment_view_keymap.collapse_comments_to_depth, |s, _| {
+            s.collapse_items_at_level(get_collapse_depth(key_sequence::get_count()));
+            Some(EventResult::Consumed(None))
+        })
+        .on_pre_event_inner(comment_view_keymap.refresh, |s, _| {
+            s.refresh();
+            Some(EventResult::Consumed(None))
+        })
+        .on_pre_event_inner(comment_view_keymap.cycle_sort_mode, |s, _| {
+            s.cycle_sort_mode();
+            Some(EventResult::Consumed(None))
+        })
+        .on_pre_event_inner(comment_view_keymap.toggle_user_metadata, |s, _| {
+            s.toggle_user_metadata();
+            Some(EventResult::Consumed(None))
+        })
+        .on_pre_event_inner(comment_view_keymap.edit_user_note, |s, _| {
+            let username = s.items[s.get_focus_index()].author.clone();
+            if username.is_empty() {
+                return Some(EventResult::Consumed(None));
+            }
+            let note = s.user_notes.get(&username).cloned().unwrap_or_default();
+            let sender = s.user_sender.clone();
+            Some(EventResult::with_cb(move |s| {
+                s.add_layer(construct_user_note_dialog(
+                    username.clone(),
+                    &note,
+                    sender.clone(),
+                ))
+            }))
+        })
+        .on_pre_event_inner(comment_view_keymap.filter_comments, |s, _| {
+            let sender = s.filter_sender.clone();
+            let query = s.filter_query.clone();
+            Some(EventResult::with_cb(move |s| {
+                s.add_layer(construct_comment_filter_dialog(&query, sender.clone()))
+            }))
+        })
         .on_pre_event(comment_view_keymap.open_article_in_browser, {
             let url = article_url.clone();
             move |_| {
@@ -415,13 +1332,115 @@ fn construct_comment_main_view(client: &'static client::HNClient, data: PageData
         .on_pre_event(config::get_global_keymap().open_help_dialog.clone(), |s| {
             s.add_layer(CommentView::construct_on_event_help_view());
         })
-        .on_scroll_events()
-        .full_height()
+        .on_pre_event(
+            config::get_global_keymap().open_command_palette.clone(),
+            move |s| {
+                s.add_layer(command_palette::construct_command_palette(
+                    client,
+                    CommentView::command_groups(),
+                ))
+            },
+        )
+        .on_mouse_events(|s| {
+            s.toggle_collapse_focused_item();
+            Some(EventResult::Consumed(None))
+        })
+        .on_scroll_events();
+
+    OnEventView::new(view).on_find_events().full_height()
+}
+
+/// Get the left margin and the padding of an item's text view based on the item's level
+fn get_item_padding(level: usize) -> (usize, TextPadding) {
+    // get the padding style (color) based on the comment's height
+    //
+    // We use base 16 colors to display the comment's padding
+    let get_style = |level: usize| -> Style { config::Color::from((level % 16) as u8).into() };
+
+    if level == 0 {
+        // add top padding for top comments, use the first color in the 16 base colors
+        return (
+            1,
+            TextPadding::default().top(StyledPaddingChar::new('▔', get_style(0))),
+        );
+    }
+
+    let config = config::get_config();
+    // cap the indentation of deeply nested comments
+    let indent_level = std::cmp::min(level, std::cmp::max(config.max_comment_indent_level, 1));
+
+    if config.use_comment_tree_guides {
+        // display a guide for each of the item's ancestors (excluding top comments) and the item itself
+        let guides = (level + 1 - indent_level..=level)
+            .map(|l| StyledPaddingChar::new('│', get_style(l)))
+            .collect();
+        let branch = StyledPaddingChar::new('├', get_style(level));
+        (1, TextPadding::default().guides(guides, Some(branch)))
+    } else {
+        (
+            indent_level * 2 + 1,
+            TextPadding::default().left(StyledPaddingChar::new('▎', get_style(level))),
+        )
+    }
+}
+
+/// Construct a dialog to edit the local note of a user
+fn construct_user_note_dialog(
+    username: String,
+    note: &str,
+    sender: std::sync::mpsc::Sender<UserUpdate>,
+) -> impl View {
+    let title = format!("Note of user {username} (empty to remove)");
+    let edit_view = EditView::new().content(note).on_submit(move |s, note| {
+        storage::set_user_note(&username, note);
+        // the comment view may have been destroyed, ignore the sending errors
+        sender
+            .send(UserUpdate::Note(username.clone()))
+            .unwrap_or_default();
+        s.pop_layer();
+    });
+
+    OnEventView::new(Dialog::around(edit_view.fixed_width(48)).title(title)).on_pre_event(
+        config::get_global_keymap().close_dialog.clone(),
+        |s| {
+            s.pop_layer();
+        },
+    )
+}
+
+/// Construct a dialog to edit the query filtering comments
+fn construct_comment_filter_dialog(
+    query: &str,
+    sender: std::sync::mpsc::Sender<String>,
+) -> impl View {
+    let edit_view = EditView::new().content(query).on_submit(move |s, query| {
+        // the comment view may have been destroyed, ignore the sending errors
+        sender.send(query.to_string()).unwrap_or_default();
+        s.pop_layer();
+    });
+
+    OnEventView::new(
+        Dialog::around(edit_view.fixed_width(48))
+            .title("Filter comments (e.g `author:pg rust`, empty to clear)"),
+    )
+    .on_pre_event(config::get_global_keymap().close_dialog.clone(), |s| {
+        s.pop_layer();
+    })
 }
 
-pub fn construct_comment_view(client: &'static client::HNClient, data: PageData) -> impl View {
+/// Construct a comment view of a Hacker News item.
+///
+/// Previews of the item should not set `persist_state`, so they don't override the thread's saved state.
+pub fn construct_comment_view(
+    client: &'static client::HNClient,
+    data: PageData,
+    focus_item_id: Option<u32>,
+    cb_sink: CbSink,
+    persist_state: bool,
+) -> impl View {
     let title = format!("Comment View - {}", data.title,);
-    let main_view = construct_comment_main_view(client, data);
+    let main_view =
+        construct_comment_main_view(client, data, focus_item_id, cb_sink, persist_state);
 
     let mut view = LinearLayout::vertical()
         .child(utils::construct_view_title_bar(&title))
@@ -433,16 +1452,49 @@ pub fn construct_comment_view(client: &'static client::HNClient, data: PageData)
     view
 }
 
-/// Retrieve comments in a Hacker News item and construct a comment view of that item
+/// Retrieve comments in a Hacker News item and construct a comment view of that item.
+///
+/// If `focus_item_id` is specified, the comment view will focus the corresponding comment once it's loaded.
 pub fn construct_and_add_new_comment_view(
     s: &mut Cursive,
     client: &'static client::HNClient,
     item_id: u32,
+    focus_item_id: Option<u32>,
     pop_layer: bool,
 ) {
-    let async_view = async_view::construct_comment_view_async(s, client, item_id);
+    let async_view = async_view::construct_comment_view_async(s, client, item_id, focus_item_id);
     if pop_layer {
         s.pop_layer();
     }
-    s.screen_mut().add_transparent_layer(Layer::new(async_view));
+    let session_view = storage::SessionView::CommentView { item_id };
+    session::add_session_layer(s, session_view, Layer::new(async_view));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collapse_depth_defaults_to_one() {
+        assert_eq!(get_collapse_depth(None), 1);
+        assert_eq!(get_collapse_depth(None), get_collapse_depth(Some(1)));
+        assert_eq!(get_collapse_depth(Some(0)), 0);
+        assert_eq!(get_collapse_depth(Some(3)), 3);
+    }
+
+    #[test]
+    fn top_level_comments_are_at_level_zero() {
+        // a story followed by two top-level comments, each with nested replies
+        let levels = [0, 0, 1, 2, 0, 1];
+        assert_eq!(get_comment_ids_at_level(&levels, 0), vec![1, 4]);
+        assert_eq!(get_comment_ids_at_level(&levels, 1), vec![2, 5]);
+        assert_eq!(get_comment_ids_at_level(&levels, 2), vec![3]);
+        assert!(get_comment_ids_at_level(&levels, 3).is_empty());
+    }
+
+    #[test]
+    fn story_is_never_collapsed() {
+        assert!(get_comment_ids_at_level(&[0], 0).is_empty());
+        assert!(get_comment_ids_at_level(&[], 0).is_empty());
+    }
 }
@@ -0,0 +1,397 @@
+use super::text_view::EditableTextView;
+use crate::prelude::*;
+
+/// FindBar is a View used to find text inside another view.
+///
+/// The find bar is displayed at the bottom of its parent view,
+/// which is responsible for highlighting the matches of the find query.
+pub struct FindBar {
+    view: LinearLayout,
+    visible: bool,
+    /// whether the find query is being edited
+    editing: bool,
+    n_matches: usize,
+    current_match: Option<usize>,
+}
+
+/// The result of highlighting matches of a find query in a text
+pub struct HighlightedText {
+    pub content: StyledString,
+    pub n_matches: usize,
+    /// the id of the span containing the start of the current match
+    pub current_span_id: Option<usize>,
+}
+
+impl FindBar {
+    pub fn new() -> Self {
+        let view = LinearLayout::horizontal()
+            .child(TextView::new(StyledString::styled(
+                "Find: ",
+                config::get_config_theme().component_style.bold,
+            )))
+            .child(EditableTextView::new())
+            // a view displaying the match counter
+            .child(TextView::new(""));
+
+        Self {
+            view,
+            visible: false,
+            editing: false,
+            n_matches: 0,
+            current_match: None,
+        }
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn is_editing(&self) -> bool {
+        self.editing
+    }
+
+    /// the height of the find bar, which is `0` if the find bar is hidden
+    pub fn height(&self) -> usize {
+        usize::from(self.visible)
+    }
+
+    pub fn current_match(&self) -> Option<usize> {
+        self.current_match
+    }
+
+    /// gets the find query, which is empty if the find bar is hidden
+    pub fn query(&self) -> String {
+        if !self.visible {
+            return String::new();
+        }
+        self.get_text_view()
+            .map(|v| v.get_text())
+            .unwrap_or_default()
+    }
+
+    /// opens the find bar to edit the find query
+    pub fn open(&mut self) {
+        self.visible = true;
+        self.editing = true;
+    }
+
+    /// stops editing the find query
+    pub fn confirm(&mut self) {
+        self.editing = false;
+    }
+
+    /// closes the find bar and clears the find query
+    pub fn close(&mut self) {
+        self.visible = false;
+        self.editing = false;
+        if let Some(view) = self.get_text_view_mut() {
+            view.set_text("");
+        }
+        self.set_matches(0, None);
+    }
+
+    /// updates the matches of the find query
+    pub fn set_matches(&mut self, n_matches: usize, current_match: Option<usize>) {
+        self.n_matches = n_matches;
+        self.current_match = current_match.filter(|&i| i < n_matches);
+        self.update_counter_view();
+    }
+
+    /// moves the current match forward (or backward) and returns the new current match
+    pub fn move_current_match(&mut self, forward: bool) -> Option<usize> {
+        if self.n_matches == 0 {
+            return None;
+        }
+        let n = self.n_matches;
+        self.current_match = Some(match self.current_match {
+            None => 0,
+            Some(i) if forward => (i + 1) % n,
+            Some(i) => (i + n - 1) % n,
+        });
+        self.update_counter_view();
+        self.current_match
+    }
+
+    fn update_counter_view(&mut self) {
+        let desc = if self.query().is_empty() {
+            String::new()
+        } else if self.n_matches == 0 {
+            " [no match]".to_string()
+        } else {
+            match self.current_match {
+                Some(i) => format!(" [{}/{}]", i + 1, self.n_matches),
+                None => format!(" [{} matches]", self.n_matches),
+            }
+        };
+        if let Some(view) = self
+            .view
+            .get_child_mut(2)
+            .and_then(|v| v.downcast_mut::<TextView>())
+        {
+            view.set_content(StyledString::styled(
+                desc,
+                config::get_config_theme().component_style.metadata,
+            ));
+        }
+    }
+
+    fn get_text_view(&self) -> Option<&EditableTextView> {
+        self.view
+            .get_child(1)
+            .and_then(|v| v.downcast_ref::<EditableTextView>())
+    }
+
+    pub fn get_text_view_mut(&mut self) -> Option<&mut EditableTextView> {
+        self.view
+            .get_child_mut(1)
+            .and_then(|v| v.downcast_mut::<EditableTextView>())
+    }
+
+    /// draws the find bar (if visible) at the bottom of the given printer
+    pub fn draw(&self, printer: &Printer) {
+        if !self.visible || printer.size.y == 0 {
+            return;
+        }
+        let printer = printer.windowed(Rect::from_size(
+            (0, printer.size.y - 1),
+            (printer.size.x, 1),
+        ));
+        self.view.draw(&printer);
+    }
+
+    pub fn layout(&mut self, size: Vec2) {
+        if self.visible {
+            self.view.layout(Vec2::new(size.x, 1));
+        }
+    }
+}
+
+impl Default for FindBar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A trait represents a view containing a find bar.
+/// It defines methods to update the view based on the find query.
+pub trait FindBarContainer {
+    fn get_find_bar(&self) -> &FindBar;
+    fn get_find_bar_mut(&mut self) -> &mut FindBar;
+
+    /// highlights matches of the find query and returns the number of matches
+    /// and the match to focus initially
+    fn find_matches(&mut self, query: &str) -> (usize, Option<usize>);
+
+    /// focuses the `i`-th match
+    fn focus_match(&mut self, i: Option<usize>);
+
+    /// updates the matches after the find query is changed
+    fn on_find_query_change(&mut self) {
+        let query = self.get_find_bar().query();
+        let (n_matches, current_match) = self.find_matches(&query);
+        self.get_find_bar_mut()
+            .set_matches(n_matches, current_match);
+        let current_match = self.get_find_bar().current_match();
+        self.focus_match(current_match);
+    }
+}
+
+impl<T> FindBarContainer for OnEventView<T>
+where
+    T: FindBarContainer,
+{
+    fn get_find_bar(&self) -> &FindBar {
+        self.get_inner().get_find_bar()
+    }
+
+    fn get_find_bar_mut(&mut self) -> &mut FindBar {
+        self.get_inner_mut().get_find_bar_mut()
+    }
+
+    fn find_matches(&mut self, query: &str) -> (usize, Option<usize>) {
+        self.get_inner_mut().find_matches(query)
+    }
+
+    fn focus_match(&mut self, i: Option<usize>) {
+        self.get_inner_mut().focus_match(i)
+    }
+}
+
+/// A trait defines the `on_find_events` method that adds
+/// callbacks to handle find events for the `self` View.
+pub trait OnFindEventView {
+    /// adds callbacks handling find events
+    fn on_find_events(self) -> Self;
+}
+
+impl<T> OnFindEventView for OnEventView<T>
+where
+    T: FindBarContainer,
+{
+    fn on_find_events(self) -> Self {
+        let find_keymap = config::get_find_keymap().clone();
+
+        // When editing the find query, key events are consumed by the find bar.
+        //
+        // As all triggered `on_pre_event` callbacks of an `OnEventView` are called,
+        // `on_find_events` is often called on an `OnEventView` wrapping the view's main `OnEventView`,
+        // so that the view's other callbacks don't receive key events when editing the find query.
+        self.on_pre_event_inner(EventTrigger::from_fn(|_| true), |s, e| {
+            if !s.get_find_bar().is_editing() {
+                return None;
+            }
+
+            let find_keymap = config::get_find_keymap();
+            let edit_keymap = config::get_edit_keymap();
+            let find_bar = s.get_find_bar_mut();
+            let query_changed = match *e {
+                Event::Char(c) => {
+                    find_bar.get_text_view_mut()?.add_char(c);
+                    true
+                }
+                _ if find_keymap.confirm_find_query.has_event(e) => {
+                    find_bar.confirm();
+                    false
+                }
+                _ if find_keymap.close_find_bar.has_event(e) => {
+                    find_bar.close();
+                    true
+                }
+                _ => {
+                    // handle editing shortcuts when editing the find query
+                    let view = find_bar.get_text_view_mut()?;
+                    if edit_keymap.backward_delete_char.has_event(e) {
+                        view.del_char();
+                        true
+                    } else {
+                        if edit_keymap.move_cursor_left.has_event(e) {
+                            view.move_cursor_left();
+                        } else if edit_keymap.move_cursor_right.has_event(e) {
+                            view.move_cursor_right();
+                        } else if edit_keymap.move_cursor_to_begin.has_event(e) {
+                            view.move_cursor_to_begin();
+                        } else if edit_keymap.move_cursor_to_end.has_event(e) {
+                            view.move_cursor_to_end();
+                        }
+                        false
+                    }
+                }
+            };
+            if query_changed {
+                s.on_find_query_change();
+            }
+            Some(EventResult::Consumed(None))
+        })
+        .on_pre_event_inner(find_keymap.open_find_bar, |s, _| {
+            s.get_find_bar_mut().open();
+            Some(EventResult::Consumed(None))
+        })
+        .on_pre_event_inner(find_keymap.close_find_bar, |s, _| {
+            if !s.get_find_bar().is_visible() {
+                return None;
+            }
+            s.get_find_bar_mut().close();
+            s.on_find_query_change();
+            Some(EventResult::Consumed(None))
+        })
+        .on_pre_event_inner(find_keymap.next_match, |s, _| {
+            if !s.get_find_bar().is_visible() {
+                return None;
+            }
+            let current_match = s.get_find_bar_mut().move_current_match(true);
+            s.focus_match(current_match);
+            Some(EventResult::Consumed(None))
+        })
+        .on_pre_event_inner(find_keymap.prev_match, |s, _| {
+            if !s.get_find_bar().is_visible() {
+                return None;
+            }
+            let current_match = s.get_find_bar_mut().move_current_match(false);
+            s.focus_match(current_match);
+            Some(EventResult::Consumed(None))
+        })
+    }
+}
+
+/// builds a case-insensitive regex matching the find query
+fn build_query_regex(query: &str) -> Option<regex::Regex> {
+    if query.is_empty() {
+        return None;
+    }
+    regex::RegexBuilder::new(&regex::escape(query))
+        .case_insensitive(true)
+        .build()
+        .ok()
+}
+
+/// counts the matches of the find query in a text
+pub fn count_matches(text: &str, query: &str) -> usize {
+    build_query_regex(query)
+        .map(|re| re.find_iter(text).count())
+        .unwrap_or_default()
+}
+
+/// highlights all matches of the find query in a styled text using the `matched_highlight` style.
+/// The `current_match`-th match is additionally highlighted in reverse.
+pub fn highlight_matches(
+    text: StyledString,
+    query: &str,
+    current_match: Option<usize>,
+) -> HighlightedText {
+    let Some(re) = build_query_regex(query) else {
+        return HighlightedText {
+            content: text,
+            n_matches: 0,
+            current_span_id: None,
+        };
+    };
+
+    // matches are found in the concatenated content of the text's spans,
+    // which can be different from the text's source
+    let source = text.spans().map(|s| s.content).collect::<String>();
+    let matches = re.find_iter(&source).map(|m| m.range()).collect::<Vec<_>>();
+
+    let highlight_style = config::get_config_theme().component_style.matched_highlight;
+    let mut content = StyledString::new();
+    let mut current_span_id = None;
+    let (mut start, mut match_id) = (0, 0);
+    for span in text.spans() {
+        let end = start + span.content.len();
+        let mut pos = start;
+        while pos < end {
+            // skip matches ending before the current position
+            while match_id < matches.len() && matches[match_id].end <= pos {
+                match_id += 1;
+            }
+            match matches.get(match_id) {
+                Some(m) if m.start < end => {
+                    if m.start > pos {
+                        content.append_styled(&source[pos..m.start], *span.attr);
+                        pos = m.start;
+                    }
+                    let match_end = m.end.min(end);
+                    let mut style = span.attr.combine(highlight_style);
+                    if Some(match_id) == current_match {
+                        style = style.combine(Effect::Reverse);
+                        if current_span_id.is_none() {
+                            current_span_id = Some(content.spans_raw().len());
+                        }
+                    }
+                    content.append_styled(&source[pos..match_end], style);
+                    pos = match_end;
+                }
+                _ => {
+                    content.append_styled(&source[pos..end], *span.attr);
+                    pos = end;
+                }
+            }
+        }
+        start = end;
+    }
+
+    HighlightedText {
+        content,
+        n_matches: matches.len(),
+        current_span_id,
+    }
+}
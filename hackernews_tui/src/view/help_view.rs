@@ -1,4 +1,6 @@
-use super::{article_view, comment_view, link_dialog, search_view, story_view, traits::*};
+use super::{
+    article_view, comment_view, history_view, link_dialog, search_view, story_view, traits::*,
+};
 use crate::prelude::*;
 
 type HelpViewContent = ScrollView<LinearLayout>;
@@ -8,6 +10,10 @@ type HelpViewContent = ScrollView<LinearLayout>;
 pub struct Command {
     keys_desc: String,
     desc: String,
+    /// the name of the command, which is the name of its keymap's field
+    name: Option<String>,
+    /// the event triggering the command
+    event: Option<Event>,
 }
 
 /// A group of command help items grouped by certain categories
@@ -16,6 +22,13 @@ pub struct CommandGroup {
     commands: Vec<Command>,
 }
 
+/// Construct a command from a keymap's field, using the field's name as the command's name
+macro_rules! command {
+    ($keymap:ident.$action:ident, $desc:expr $(,)?) => {
+        Command::with_keys(&$keymap.$action, $desc).name(stringify!($action))
+    };
+}
+
 impl Command {
     pub fn new<X, Y>(keys_desc: X, desc: Y) -> Self
     where
@@ -25,9 +38,48 @@ impl Command {
         Self {
             keys_desc: keys_desc.into(),
             desc: desc.into(),
+            name: None,
+            event: None,
+        }
+    }
+
+    /// constructs a command triggered by the given keys
+    pub fn with_keys<Y>(keys: &config::Keys, desc: Y) -> Self
+    where
+        Y: Into<String>,
+    {
+        Self {
+            event: keys.first_event(),
+            ..Self::new(keys.to_string(), desc)
         }
     }
 
+    pub fn name<T>(self, name: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self {
+            name: Some(name.into()),
+            ..self
+        }
+    }
+
+    pub fn get_name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn get_desc(&self) -> &str {
+        &self.desc
+    }
+
+    pub fn get_keys_desc(&self) -> &str {
+        &self.keys_desc
+    }
+
+    pub fn get_event(&self) -> Option<&Event> {
+        self.event.as_ref()
+    }
+
     /// converts into a command View which consists of
     /// - a keybindings text View
     /// - a command description text View
@@ -62,6 +114,10 @@ impl CommandGroup {
         }
     }
 
+    pub fn into_commands(self) -> Vec<Command> {
+        self.commands
+    }
+
     /// converts into a command group View which consists of multiple command View(s)
     pub fn to_group_view(self) -> impl View {
         let max_keys_len = match self
@@ -155,7 +211,12 @@ impl ScrollViewContainer for HelpView {
 }
 
 pub trait HasHelpView {
-    fn construct_help_view() -> HelpView;
+    /// gets the commands supported by the view, grouped by categories
+    fn command_groups() -> Vec<CommandGroup>;
+
+    fn construct_help_view() -> HelpView {
+        HelpView::new().command_groups(Self::command_groups())
+    }
 
     fn construct_on_event_help_view() -> OnEventView<HelpView> {
         OnEventView::new(Self::construct_help_view())
@@ -172,54 +233,49 @@ pub trait HasHelpView {
 pub struct DefaultHelpView {}
 
 impl HasHelpView for DefaultHelpView {
-    fn construct_help_view() -> HelpView {
-        HelpView::new().command_groups(vec![
+    fn command_groups() -> Vec<CommandGroup> {
+        vec![
             CommandGroup::new("View navigation", default_view_navigation_commands()),
             CommandGroup::new("Other", default_other_commands()),
-        ])
+        ]
     }
 }
 
 fn default_other_commands() -> Vec<Command> {
     let global_keymap = config::get_global_keymap();
     vec![
-        Command::new(
-            global_keymap.open_help_dialog.to_string(),
-            "Open the help dialog",
+        command!(global_keymap.open_help_dialog, "Open the help dialog"),
+        command!(
+            global_keymap.open_command_palette,
+            "Open the command palette to find and run a command",
         ),
-        Command::new(global_keymap.quit.to_string(), "Quit the application"),
-        Command::new(global_keymap.close_dialog.to_string(), "Close a dialog"),
+        command!(global_keymap.quit, "Quit the application"),
+        command!(global_keymap.close_dialog, "Close a dialog"),
     ]
 }
 
 fn default_view_navigation_commands() -> Vec<Command> {
     let global_keymap = config::get_global_keymap();
     vec![
-        Command::new(
-            global_keymap.goto_previous_view.to_string(),
-            "Go to the previous view",
-        ),
-        Command::new(
-            global_keymap.goto_search_view.to_string(),
-            "Go to search view",
-        ),
-        Command::new(
-            global_keymap.goto_front_page_view.to_string(),
-            "Go to front page view",
+        command!(global_keymap.goto_previous_view, "Go to the previous view"),
+        command!(
+            global_keymap.goto_next_view,
+            "Go to the next view (after going back)",
         ),
-        Command::new(
-            global_keymap.goto_all_stories_view.to_string(),
+        command!(global_keymap.goto_history_view, "Go to history view"),
+        command!(global_keymap.goto_search_view, "Go to search view"),
+        command!(global_keymap.goto_front_page_view, "Go to front page view"),
+        command!(
+            global_keymap.goto_all_stories_view,
             "Go to all stories view",
         ),
-        Command::new(
-            global_keymap.goto_ask_hn_view.to_string(),
-            "Go to ask HN view",
-        ),
-        Command::new(
-            global_keymap.goto_show_hn_view.to_string(),
-            "Go to show HN view",
-        ),
-        Command::new(global_keymap.goto_jobs_view.to_string(), "Go to jobs view"),
+        command!(global_keymap.goto_ask_hn_view, "Go to ask HN view"),
+        command!(global_keymap.goto_show_hn_view, "Go to show HN view"),
+        command!(global_keymap.goto_jobs_view, "Go to jobs view"),
+        command!(global_keymap.new_tab, "Open front page view in a new tab"),
+        command!(global_keymap.close_tab, "Close the current tab"),
+        command!(global_keymap.next_tab, "Go to the next tab"),
+        command!(global_keymap.prev_tab, "Go to the previous tab"),
     ]
 }
 
@@ -227,31 +283,44 @@ fn default_scroll_commands() -> Vec<Command> {
     let scroll_keymap = config::get_scroll_keymap();
 
     vec![
-        Command::new(scroll_keymap.up.to_string(), "Scroll up"),
-        Command::new(scroll_keymap.down.to_string(), "Scroll down"),
-        Command::new(scroll_keymap.page_up.to_string(), "Scroll page up"),
-        Command::new(scroll_keymap.page_down.to_string(), "Scroll page down"),
-        Command::new(scroll_keymap.top.to_string(), "Scroll to top"),
-        Command::new(scroll_keymap.bottom.to_string(), "Scroll to bottom"),
+        command!(scroll_keymap.up, "Scroll up"),
+        command!(scroll_keymap.down, "Scroll down"),
+        command!(scroll_keymap.page_up, "Scroll page up"),
+        command!(scroll_keymap.page_down, "Scroll page down"),
+        command!(scroll_keymap.top, "Scroll to top"),
+        command!(scroll_keymap.bottom, "Scroll to bottom"),
+    ]
+}
+
+fn default_find_commands() -> Vec<Command> {
+    let find_keymap = config::get_find_keymap();
+
+    vec![
+        command!(find_keymap.open_find_bar, "Open the find bar"),
+        command!(
+            find_keymap.close_find_bar,
+            "Close the find bar and clear the highlighted matches",
+        ),
+        command!(
+            find_keymap.confirm_find_query,
+            "Stop editing the find query",
+        ),
+        command!(find_keymap.next_match, "Focus the next match"),
+        command!(find_keymap.prev_match, "Focus the previous match"),
     ]
 }
 
 impl HasHelpView for story_view::StoryView {
-    fn construct_help_view() -> HelpView {
+    fn command_groups() -> Vec<CommandGroup> {
         let story_view_keymap = config::get_story_view_keymap();
+        let split_view_keymap = config::get_split_view_keymap();
 
-        let mut help_view = HelpView::new().command_groups(vec![
+        let mut groups = vec![
             CommandGroup::new(
                 "Story navigation",
                 vec![
-                    Command::new(
-                        story_view_keymap.next_story.to_string(),
-                        "Focus the next story",
-                    ),
-                    Command::new(
-                        story_view_keymap.prev_story.to_string(),
-                        "Focus the previous story",
-                    ),
+                    command!(story_view_keymap.next_story, "Focus the next story"),
+                    command!(story_view_keymap.prev_story, "Focus the previous story"),
                     Command::new(
                         format!("{{story_id}} {}", story_view_keymap.goto_story),
                         "Focus the {story_id}-th story",
@@ -261,16 +330,10 @@ impl HasHelpView for story_view::StoryView {
             CommandGroup::new(
                 "Paging/Filtering",
                 vec![
-                    Command::new(
-                        story_view_keymap.next_page.to_string(),
-                        "Go to the next page",
-                    ),
-                    Command::new(
-                        story_view_keymap.prev_page.to_string(),
-                        "Go the previous page",
-                    ),
-                    Command::new(
-                        story_view_keymap.cycle_sort_mode.to_string(),
+                    command!(story_view_keymap.next_page, "Go to the next page"),
+                    command!(story_view_keymap.prev_page, "Go the previous page"),
+                    command!(
+                        story_view_keymap.cycle_sort_mode,
                         "Cycle story sort mode (not for `front_page` story views)",
                     ),
                 ],
@@ -278,66 +341,97 @@ impl HasHelpView for story_view::StoryView {
             CommandGroup::new(
                 "Links",
                 vec![
-                    Command::new(
-                        story_view_keymap.open_article_in_browser.to_string(),
+                    command!(
+                        story_view_keymap.open_article_in_browser,
                         "Open in browser the focused story's article",
                     ),
-                    Command::new(
-                        story_view_keymap.open_article_in_article_view.to_string(),
+                    command!(
+                        story_view_keymap.open_article_in_article_view,
                         "Open in article view the focused story's article",
                     ),
-                    Command::new(
-                        story_view_keymap.open_story_in_browser.to_string(),
+                    command!(
+                        story_view_keymap.open_story_in_browser,
                         "Open in browser the focused story",
                     ),
                 ],
             ),
-        ]);
+            CommandGroup::new(
+                "Tabs",
+                vec![
+                    command!(
+                        story_view_keymap.open_story_in_new_tab,
+                        "Open in a new tab the comment view of the focused story",
+                    ),
+                    command!(
+                        story_view_keymap.open_article_in_new_tab,
+                        "Open in a new tab the article view of the focused story's article",
+                    ),
+                ],
+            ),
+            CommandGroup::new(
+                "Split view",
+                vec![
+                    command!(
+                        split_view_keymap.toggle_pane_focus,
+                        "Toggle the focus between the story view and the preview",
+                    ),
+                    command!(
+                        split_view_keymap.toggle_preview_mode,
+                        "Toggle previewing the focused story's comments or article",
+                    ),
+                ],
+            ),
+        ];
 
         let custom_commands = config::get_config()
             .keymap
             .custom_keymaps
             .iter()
             .map(|keymap| {
-                Command::new(
-                    keymap.key.to_string(),
-                    format!(
-                        "Go to {} view (by_date: {}, {})",
-                        match keymap.tag.as_str() {
-                            "front_page" => "front page",
-                            "story" => "all stories",
-                            "job" => "jobs",
-                            "ask_hn" => "ask HN",
-                            "show_hn" => "show HN",
-                            _ => panic!("unknown view: {}", keymap.tag),
-                        },
-                        keymap.by_date,
-                        keymap.numeric_filters.desc()
-                    ),
+                Command::with_keys(
+                    &keymap.key,
+                    match &keymap.view {
+                        config::CustomView::StoryView {
+                            tag,
+                            by_date,
+                            numeric_filters,
+                        } => format!(
+                            "Go to {} view (by_date: {}, {})",
+                            match tag.as_str() {
+                                "front_page" => "front page",
+                                "story" => "all stories",
+                                "job" => "jobs",
+                                "ask_hn" => "ask HN",
+                                "show_hn" => "show HN",
+                                _ => panic!("unknown view: {}", tag),
+                            },
+                            by_date,
+                            numeric_filters.desc()
+                        ),
+                        config::CustomView::SavedSearch { saved_search } => {
+                            format!("Go to the saved search \"{saved_search}\"")
+                        }
+                    },
                 )
             })
             .collect::<Vec<_>>();
 
         if !custom_commands.is_empty() {
-            help_view = help_view
-                .command_groups(vec![CommandGroup::new("Custom keymaps", custom_commands)]);
+            groups.push(CommandGroup::new("Custom keymaps", custom_commands));
         }
 
-        help_view.command_groups(vec![
+        groups.extend([
             CommandGroup::new(
                 "View navigation",
                 [
                     vec![
-                        Command::new(
-                            story_view_keymap.goto_story_comment_view.to_string(),
+                        command!(
+                            story_view_keymap.goto_story_comment_view,
                             "Go to the comment view associated with the focused story",
                         ),
-                        Command::new(
-                            story_view_keymap.next_story_tag.to_string(),
-                            "Go to the next story tag",
-                        ),
-                        Command::new(
-                            story_view_keymap.prev_story_tag.to_string(),
+                        command!(story_view_keymap.next_story_tag, "Go to the next story tag"),
+                        command!(
+                            story_view_keymap.prev_story_tag,
                             "Go to the previous story tag",
                         ),
                     ],
@@ -347,65 +441,71 @@ impl HasHelpView for story_view::StoryView {
             ),
             CommandGroup::new("Scrolling", default_scroll_commands()),
             CommandGroup::new("Others", default_other_commands()),
-        ])
+        ]);
+        groups
     }
 }
 
 impl HasHelpView for comment_view::CommentView {
-    fn construct_help_view() -> HelpView {
+    fn command_groups() -> Vec<CommandGroup> {
         let comment_view_keymap = config::get_comment_view_keymap();
 
-        HelpView::new().command_groups(vec![
+        vec![
             CommandGroup::new(
                 "Comment navigation",
                 vec![
-                    Command::new(
-                        comment_view_keymap.next_comment.to_string(),
-                        "Focus the next comment",
-                    ),
-                    Command::new(
-                        comment_view_keymap.prev_comment.to_string(),
+                    command!(comment_view_keymap.next_comment, "Focus the next comment"),
+                    command!(
+                        comment_view_keymap.prev_comment,
                         "Focus the previous comment",
                     ),
-                    Command::new(
-                        comment_view_keymap.next_top_level_comment.to_string(),
+                    command!(
+                        comment_view_keymap.next_top_level_comment,
                         "Focus the next top level comment",
                     ),
-                    Command::new(
-                        comment_view_keymap.prev_top_level_comment.to_string(),
+                    command!(
+                        comment_view_keymap.prev_top_level_comment,
                         "Focus the previous top level comment",
                     ),
-                    Command::new(
-                        comment_view_keymap.next_leq_level_comment.to_string(),
+                    command!(
+                        comment_view_keymap.next_leq_level_comment,
                         "Focus the next comment at smaller or equal level",
                     ),
-                    Command::new(
-                        comment_view_keymap.prev_leq_level_comment.to_string(),
+                    command!(
+                        comment_view_keymap.prev_leq_level_comment,
                         "Focus the previous comment at smaller or equal level",
                     ),
-                    Command::new(
-                        comment_view_keymap.parent_comment.to_string(),
+                    command!(
+                        comment_view_keymap.parent_comment,
                         "Focus the parent comment (if exists)",
                     ),
+                    command!(
+                        comment_view_keymap.next_op_comment,
+                        "Focus the next comment posted by the story's author",
+                    ),
+                    command!(
+                        comment_view_keymap.prev_op_comment,
+                        "Focus the previous comment posted by the story's author",
+                    ),
                 ],
             ),
             CommandGroup::new(
                 "Links",
                 vec![
-                    Command::new(
-                        comment_view_keymap.open_article_in_browser.to_string(),
+                    command!(
+                        comment_view_keymap.open_article_in_browser,
                         "Open in browser the dicussed article",
                     ),
-                    Command::new(
-                        comment_view_keymap.open_article_in_article_view.to_string(),
+                    command!(
+                        comment_view_keymap.open_article_in_article_view,
                         "Open in article view the dicussed article",
                     ),
-                    Command::new(
-                        comment_view_keymap.open_story_in_browser.to_string(),
+                    command!(
+                        comment_view_keymap.open_story_in_browser,
                         "Open in browser the discussed story",
                     ),
-                    Command::new(
-                        comment_view_keymap.open_comment_in_browser.to_string(),
+                    command!(
+                        comment_view_keymap.open_comment_in_browser,
                         "Open in browser the focused comment",
                     ),
                     Command::new(
@@ -422,58 +522,100 @@ impl HasHelpView for comment_view::CommentView {
                 ],
             ),
             CommandGroup::new("Scrolling", default_scroll_commands()),
+            CommandGroup::new("Find", default_find_commands()),
             CommandGroup::new("View navigation", default_view_navigation_commands()),
             CommandGroup::new(
                 "Others",
                 [
                     vec![
-                        Command::new(
-                            comment_view_keymap.toggle_collapse_comment.to_string(),
+                        command!(
+                            comment_view_keymap.toggle_collapse_comment,
                             "Toggle collapsing the focused item",
                         ),
+                        command!(
+                            comment_view_keymap.collapse_all_comments,
+                            "Collapse all top-level comments",
+                        ),
+                        command!(
+                            comment_view_keymap.expand_all_comments,
+                            "Expand all comments",
+                        ),
                         Command::new(
-                            comment_view_keymap.vote.to_string(),
-                            "Toggle voting the focused item",
+                            format!(
+                                "{{depth}} {}",
+                                comment_view_keymap.collapse_comments_to_depth
+                            ),
+                            "Collapse comments nested deeper than {depth} (default: 1)",
+                        ),
+                        command!(comment_view_keymap.vote, "Toggle voting the focused item"),
+                        command!(
+                            comment_view_keymap.refresh,
+                            "Refresh comments, preserving the collapse state",
+                        ),
+                        command!(
+                            comment_view_keymap.cycle_sort_mode,
+                            "Cycle the sort mode of comments",
+                        ),
+                        command!(
+                            comment_view_keymap.filter_comments,
+                            "Filter comments by author or text",
+                        ),
+                        command!(
+                            comment_view_keymap.toggle_user_metadata,
+                            "Toggle showing the karma and the account age of comments' authors",
+                        ),
+                        command!(
+                            comment_view_keymap.edit_user_note,
+                            "Edit the local note of the focused comment's author",
                         ),
                     ],
                     default_other_commands(),
                 ]
                 .concat(),
             ),
-        ])
+        ]
     }
 }
 
 impl HasHelpView for search_view::SearchView {
-    fn construct_help_view() -> HelpView {
+    fn command_groups() -> Vec<CommandGroup> {
         let search_view_keymap = config::get_search_view_keymap();
         let story_view_keymap = config::get_story_view_keymap();
 
-        HelpView::new().command_groups(vec![
+        vec![
             CommandGroup::new(
                 "Switch Mode",
                 vec![
-                    Command::new(
-                        search_view_keymap.to_navigation_mode.to_string(),
+                    command!(
+                        search_view_keymap.to_navigation_mode,
                         "Switch to navigation mode",
                     ),
-                    Command::new(
-                        search_view_keymap.to_search_mode.to_string(),
-                        "Switch to search mode",
-                    ),
+                    command!(search_view_keymap.to_search_mode, "Switch to search mode"),
                 ],
             ),
             CommandGroup::new(
-                "Navigation Mode - Story navigation",
+                "Search",
                 vec![
+                    command!(
+                        search_view_keymap.toggle_search_target,
+                        "Toggle searching stories or comments",
+                    ),
+                    command!(search_view_keymap.save_search, "Save the current search"),
                     Command::new(
-                        story_view_keymap.next_story.to_string(),
-                        "Focus the next story",
+                        config::get_edit_keymap().prev_history_entry.to_string(),
+                        "Search mode - Go to the previous query in the search history",
                     ),
                     Command::new(
-                        story_view_keymap.prev_story.to_string(),
-                        "Focus the previous story",
+                        config::get_edit_keymap().next_history_entry.to_string(),
+                        "Search mode - Go to the next query in the search history",
                     ),
+                ],
+            ),
+            CommandGroup::new(
+                "Navigation Mode - Story navigation",
+                vec![
+                    command!(story_view_keymap.next_story, "Focus the next story"),
+                    command!(story_view_keymap.prev_story, "Focus the previous story"),
                     Command::new(
                         format!("{{story_id}} {}", story_view_keymap.goto_story),
                         "Focus the {story_id}-th story",
@@ -483,63 +625,87 @@ impl HasHelpView for search_view::SearchView {
             CommandGroup::new(
                 "Navigation Mode - Paging/Filtering",
                 vec![
-                    Command::new(
-                        story_view_keymap.next_page.to_string(),
-                        "Go to the next page",
-                    ),
-                    Command::new(
-                        story_view_keymap.prev_page.to_string(),
-                        "Go the previous page",
-                    ),
-                    Command::new(
-                        story_view_keymap.cycle_sort_mode.to_string(),
-                        "Cycle story sort mode",
-                    ),
+                    command!(story_view_keymap.next_page, "Go to the next page"),
+                    command!(story_view_keymap.prev_page, "Go the previous page"),
+                    command!(story_view_keymap.cycle_sort_mode, "Cycle story sort mode"),
                 ],
             ),
             CommandGroup::new(
                 "Navigation Mode - Links",
                 vec![
-                    Command::new(
-                        story_view_keymap.open_article_in_browser.to_string(),
+                    command!(
+                        story_view_keymap.open_article_in_browser,
                         "Open in browser the focused story's article",
                     ),
-                    Command::new(
-                        story_view_keymap.open_article_in_article_view.to_string(),
+                    command!(
+                        story_view_keymap.open_article_in_article_view,
                         "Open in article view the focused story's article",
                     ),
+                    command!(
+                        story_view_keymap.open_story_in_browser,
+                        "Open in browser the focused story (or comment)",
+                    ),
+                ],
+            ),
+            CommandGroup::new(
+                "View navigation",
+                [
+                    vec![command!(
+                        story_view_keymap.goto_story_comment_view,
+                        "Go to the comment view associated with the focused story (or comment)",
+                    )],
+                    default_view_navigation_commands(),
+                ]
+                .concat(),
+            ),
+            CommandGroup::new("Others", default_other_commands()),
+        ]
+    }
+}
+
+impl HasHelpView for history_view::HistoryView {
+    fn command_groups() -> Vec<CommandGroup> {
+        let story_view_keymap = config::get_story_view_keymap();
+
+        vec![
+            CommandGroup::new(
+                "Navigation",
+                vec![
+                    command!(story_view_keymap.next_story, "Focus the next entry"),
+                    command!(story_view_keymap.prev_story, "Focus the previous entry"),
                     Command::new(
-                        story_view_keymap.open_story_in_browser.to_string(),
-                        "Open in browser the focused story",
+                        format!("{{entry_id}} {}", story_view_keymap.goto_story),
+                        "Focus the {entry_id}-th entry",
                     ),
                 ],
             ),
             CommandGroup::new(
                 "View navigation",
                 [
-                    vec![Command::new(
-                        story_view_keymap.goto_story_comment_view.to_string(),
-                        "Go to the comment view associated with the focused story",
+                    vec![command!(
+                        story_view_keymap.goto_story_comment_view,
+                        "Reopen the view of the focused entry",
                     )],
                     default_view_navigation_commands(),
                 ]
                 .concat(),
             ),
             CommandGroup::new("Others", default_other_commands()),
-        ])
+        ]
     }
 }
 
 impl HasHelpView for article_view::ArticleView {
-    fn construct_help_view() -> HelpView {
+    fn command_groups() -> Vec<CommandGroup> {
         let article_view_keymap = config::get_article_view_keymap().clone();
-        HelpView::new().command_groups(vec![
+        vec![
             CommandGroup::new("Scrolling", default_scroll_commands()),
+            CommandGroup::new("Find", default_find_commands()),
             CommandGroup::new(
                 "Links",
                 vec![
-                    Command::new(
-                        article_view_keymap.open_article_in_browser.to_string(),
+                    command!(
+                        article_view_keymap.open_article_in_browser,
                         "Open the current article in browser",
                     ),
                     Command::new(
@@ -553,44 +719,41 @@ impl HasHelpView for article_view::ArticleView {
                         ),
                         "Open in article view the {link_id}-th link",
                     ),
-                    Command::new(
-                        article_view_keymap.open_link_dialog.to_string(),
-                        "Open a link dialog",
-                    ),
+                    command!(article_view_keymap.open_link_dialog, "Open a link dialog"),
                 ],
             ),
             CommandGroup::new("View navigation", default_view_navigation_commands()),
             CommandGroup::new("Others", default_other_commands()),
-        ])
+        ]
     }
 }
 
 impl HasHelpView for link_dialog::LinkDialog {
-    fn construct_help_view() -> HelpView {
+    fn command_groups() -> Vec<CommandGroup> {
         let link_dialog_keymap = config::get_link_dialog_keymap().clone();
-        HelpView::new().command_groups(vec![
+        vec![
             CommandGroup::new(
                 "Link navigation",
                 vec![
-                    Command::new(link_dialog_keymap.next.to_string(), "Focus next"),
-                    Command::new(link_dialog_keymap.prev.to_string(), "Focus prev"),
+                    command!(link_dialog_keymap.next, "Focus next"),
+                    command!(link_dialog_keymap.prev, "Focus prev"),
                 ],
             ),
             CommandGroup::new(
                 "Links",
                 vec![
-                    Command::new(
-                        link_dialog_keymap.open_link_in_browser.to_string(),
+                    command!(
+                        link_dialog_keymap.open_link_in_browser,
                         "Open in browser the focused link",
                     ),
-                    Command::new(
-                        link_dialog_keymap.open_link_in_article_view.to_string(),
+                    command!(
+                        link_dialog_keymap.open_link_in_article_view,
                         "Open in article view the focused link",
                     ),
                 ],
             ),
             CommandGroup::new("View navigation", default_view_navigation_commands()),
             CommandGroup::new("Others", default_other_commands()),
-        ])
+        ]
     }
 }
@@ -0,0 +1,164 @@
+use super::{
+    command_palette, help_view::HasHelpView, key_sequence, session, text_view, traits::*, utils,
+};
+use crate::prelude::*;
+use crate::storage::{self, HistoryEntry};
+
+/// HistoryView is a View displaying the recently opened views
+pub struct HistoryView {
+    entries: Vec<HistoryEntry>,
+
+    view: ScrollView<LinearLayout>,
+}
+
+impl ViewWrapper for HistoryView {
+    wrap_impl!(self.view: ScrollView<LinearLayout>);
+}
+
+impl HistoryView {
+    pub fn new(entries: Vec<HistoryEntry>) -> Self {
+        let view = LinearLayout::vertical()
+            .with(|s| {
+                entries.iter().enumerate().for_each(|(i, e)| {
+                    s.add_child(PaddedView::lrtb(
+                        0,
+                        0,
+                        0,
+                        1,
+                        text_view::TextView::new(Self::get_entry_text(i + 1, e)),
+                    ));
+                })
+            })
+            .scrollable();
+
+        HistoryView { entries, view }
+    }
+
+    /// Get the text displaying a history entry and the time it was opened
+    fn get_entry_text(id: usize, e: &HistoryEntry) -> StyledString {
+        let component_style = &config::get_config_theme().component_style;
+
+        let mut text = StyledString::styled(format!("{id}. "), component_style.metadata);
+        text.append_styled(&e.title, component_style.bold);
+        text.append_styled(
+            format!(
+                "\nopened {} ago",
+                crate::utils::get_elapsed_time_as_text(e.time)
+            ),
+            component_style.metadata,
+        );
+        text
+    }
+
+    inner_getters!(self.view: ScrollView<LinearLayout>);
+}
+
+impl ListViewContainer for HistoryView {
+    fn get_inner_list(&self) -> &LinearLayout {
+        self.get_inner().get_inner()
+    }
+
+    fn get_inner_list_mut(&mut self) -> &mut LinearLayout {
+        self.get_inner_mut().get_inner_mut()
+    }
+
+    fn on_set_focus_index(&mut self, old_id: usize, new_id: usize) {
+        let direction = old_id <= new_id;
+
+        // enable auto-scrolling when changing the focused index of the view
+        self.scroll(direction);
+    }
+}
+
+impl ScrollViewContainer for HistoryView {
+    type ScrollInner = LinearLayout;
+
+    fn get_inner_scroll_view(&self) -> &ScrollView<LinearLayout> {
+        self.get_inner()
+    }
+
+    fn get_inner_scroll_view_mut(&mut self) -> &mut ScrollView<LinearLayout> {
+        self.get_inner_mut()
+    }
+}
+
+/// Construct a history view's main view.
+///
+/// The view re-uses the story view's keymaps to navigate between entries.
+fn construct_history_main_view(
+    entries: Vec<HistoryEntry>,
+    client: &'static client::HNClient,
+) -> OnEventView<HistoryView> {
+    let story_view_keymap = config::get_story_view_keymap().clone();
+
+    let open_entry_view = move |s: &mut HistoryView| {
+        let view = s.entries.get(s.get_focus_index())?.view.clone();
+        Some(EventResult::with_cb(move |s| {
+            session::construct_and_add_session_view(s, client, view.clone())
+        }))
+    };
+
+    OnEventView::new(HistoryView::new(entries))
+        // don't allow the inner `LinearLayout` child view to handle the event
+        // because of its pre-defined `on_event` function
+        .on_pre_event_inner(EventTrigger::from_fn(|_| true), |_, _| {
+            Some(EventResult::Ignored)
+        })
+        .on_pre_event_inner(story_view_keymap.prev_story, |s, _| {
+            let id = s.get_focus_index();
+            if id == 0 {
+                None
+            } else {
+                s.set_focus_index(id - 1)
+            }
+        })
+        .on_pre_event_inner(story_view_keymap.next_story, |s, _| {
+            let id = s.get_focus_index();
+            s.set_focus_index(id + 1)
+        })
+        .on_pre_event_inner(story_view_keymap.goto_story_comment_view, move |s, _| {
+            open_entry_view(s)
+        })
+        .on_pre_event_inner(story_view_keymap.goto_story, |s, _| {
+            let number = key_sequence::get_count()?;
+            if number == 0 || number > s.len() {
+                return None;
+            }
+            s.set_focus_index(number - 1).unwrap();
+            Some(EventResult::Consumed(None))
+        })
+        .on_mouse_events(open_entry_view)
+        .on_scroll_events()
+}
+
+/// Construct a history view listing the recently opened views
+pub fn construct_history_view(client: &'static client::HNClient) -> impl View {
+    let main_view = construct_history_main_view(storage::get_history(), client).full_height();
+
+    let mut view = LinearLayout::vertical()
+        .child(utils::construct_view_title_bar("History View"))
+        .child(main_view)
+        .child(utils::construct_footer_view::<HistoryView>());
+    view.set_focus_index(1)
+        .unwrap_or(EventResult::Consumed(None));
+
+    OnEventView::new(view)
+        .on_pre_event(config::get_global_keymap().open_help_dialog.clone(), |s| {
+            s.add_layer(HistoryView::construct_on_event_help_view())
+        })
+        .on_pre_event(
+            config::get_global_keymap().open_command_palette.clone(),
+            move |s| {
+                s.add_layer(command_palette::construct_command_palette(
+                    client,
+                    HistoryView::command_groups(),
+                ))
+            },
+        )
+}
+
+/// Construct a history view and add it to the screen
+pub fn construct_and_add_new_history_view(s: &mut Cursive, client: &'static client::HNClient) {
+    s.screen_mut()
+        .add_transparent_layer(Layer::new(construct_history_view(client)));
+}
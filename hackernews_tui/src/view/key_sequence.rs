@@ -0,0 +1,260 @@
+//! Handling of key sequences (e.g. `gg` or `<space>c`) and count prefixes (e.g. `5j`)
+//! shared by all views of the application.
+//!
+//! A count prefix is a number typed before a command. Movement commands are repeated
+//! `count` times, while other commands can get the count with `get_count`.
+
+use crate::prelude::*;
+use once_cell::sync::Lazy;
+use std::sync::Mutex;
+use unicode_width::UnicodeWidthStr;
+
+/// the maximum number of digits in a count prefix
+const MAX_COUNT_DIGITS: usize = 4;
+
+/// all multi-key sequences defined in the application's keymap
+static KEY_SEQUENCES: Lazy<Vec<&'static [Event]>> =
+    Lazy::new(|| config::get_config().keymap.get_key_sequences());
+
+static PENDING_KEYS: Mutex<PendingKeys> = Mutex::new(PendingKeys::new());
+
+/// The state of keys typed but not yet dispatched to views
+struct PendingKeys {
+    /// the count prefix being typed
+    count: String,
+    /// the key sequence being typed
+    keys: Vec<Event>,
+    /// the count of the command being run
+    running_count: Option<usize>,
+    /// whether pending keys are being replayed to views
+    replaying: bool,
+}
+
+impl PendingKeys {
+    const fn new() -> Self {
+        Self {
+            count: String::new(),
+            keys: Vec::new(),
+            running_count: None,
+            replaying: false,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.count.is_empty() && self.keys.is_empty()
+    }
+
+    fn clear(&mut self) {
+        self.count.clear();
+        self.keys.clear();
+    }
+}
+
+fn lock_pending_keys() -> std::sync::MutexGuard<'static, PendingKeys> {
+    PENDING_KEYS.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+/// Get the count prefix of the command being run
+pub fn get_count() -> Option<usize> {
+    lock_pending_keys().running_count
+}
+
+/// Get the description of the count prefix and the key sequence being typed
+fn get_pending_keys_desc() -> String {
+    let state = lock_pending_keys();
+    format!("{}{}", state.count, config::KeySequence(&state.keys))
+}
+
+fn is_key_event(e: &Event) -> bool {
+    matches!(
+        e,
+        Event::Char(_)
+            | Event::CtrlChar(_)
+            | Event::AltChar(_)
+            | Event::Key(_)
+            | Event::Shift(_)
+            | Event::Alt(_)
+            | Event::AltShift(_)
+            | Event::Ctrl(_)
+            | Event::CtrlShift(_)
+            | Event::CtrlAlt(_)
+    )
+}
+
+/// checks if a list of keys is a strict prefix of a key sequence
+fn is_sequence_prefix(keys: &[Event]) -> bool {
+    KEY_SEQUENCES
+        .iter()
+        .any(|s| s.len() > keys.len() && s.starts_with(keys))
+}
+
+/// checks if an event triggers a movement command, which is repeated by the count prefix
+fn is_movement_event(e: &Event) -> bool {
+    let scroll_keymap = config::get_scroll_keymap();
+    let find_keymap = config::get_find_keymap();
+    let command_palette_keymap = config::get_command_palette_keymap();
+    let story_view_keymap = config::get_story_view_keymap();
+    let comment_view_keymap = config::get_comment_view_keymap();
+    let link_dialog_keymap = config::get_link_dialog_keymap();
+
+    [
+        &scroll_keymap.up,
+        &scroll_keymap.down,
+        &scroll_keymap.page_up,
+        &scroll_keymap.page_down,
+        &find_keymap.next_match,
+        &find_keymap.prev_match,
+        &command_palette_keymap.next_command,
+        &command_palette_keymap.prev_command,
+        &story_view_keymap.next_story,
+        &story_view_keymap.prev_story,
+        &comment_view_keymap.next_comment,
+        &comment_view_keymap.prev_comment,
+        &comment_view_keymap.next_top_level_comment,
+        &comment_view_keymap.prev_top_level_comment,
+        &comment_view_keymap.next_leq_level_comment,
+        &comment_view_keymap.prev_leq_level_comment,
+        &comment_view_keymap.parent_comment,
+        &comment_view_keymap.next_op_comment,
+        &comment_view_keymap.prev_op_comment,
+        &link_dialog_keymap.next,
+        &link_dialog_keymap.prev,
+    ]
+    .iter()
+    .any(|keys| keys.has_event(e))
+}
+
+/// Replay events to views, with `count` as the count prefix of the first event
+fn replay_events(s: &mut Cursive, events: &[Event], count: Option<usize>) {
+    for (i, e) in events.iter().enumerate() {
+        let count = if i == 0 { count } else { None };
+        let repeat = if is_movement_event(e) {
+            count.unwrap_or(1)
+        } else {
+            1
+        };
+
+        {
+            let mut state = lock_pending_keys();
+            state.replaying = true;
+            state.running_count = count;
+        }
+        for _ in 0..repeat {
+            s.on_event(e.clone());
+        }
+        {
+            let mut state = lock_pending_keys();
+            state.replaying = false;
+            state.running_count = None;
+        }
+    }
+}
+
+/// A callback run before views handle a key event.
+///
+/// If there are pending keys, the callback takes over the event
+/// and dispatches the command triggered by the pending keys and the event.
+fn on_pre_key_event(e: &Event) -> Option<EventResult> {
+    let mut state = lock_pending_keys();
+    if state.replaying || state.is_empty() {
+        return None;
+    }
+
+    if *e == Event::Key(Key::Esc) {
+        state.clear();
+        return Some(EventResult::Consumed(None));
+    }
+
+    let events = if state.keys.is_empty() {
+        match e {
+            // digits are handled by `on_post_key_event` to extend the count prefix
+            Event::Char(c) if c.is_ascii_digit() => return None,
+            _ if is_sequence_prefix(std::slice::from_ref(e)) => {
+                state.keys.push(e.clone());
+                return Some(EventResult::Consumed(None));
+            }
+            _ => vec![e.clone()],
+        }
+    } else {
+        let mut keys = state.keys.clone();
+        keys.push(e.clone());
+        if KEY_SEQUENCES.contains(&keys.as_slice()) {
+            vec![config::get_sequence_event(&keys)]
+        } else if is_sequence_prefix(&keys) {
+            state.keys = keys;
+            return Some(EventResult::Consumed(None));
+        } else if state.count.is_empty() {
+            // the keys don't form any sequence, replay them one by one.
+            // Without a count prefix, the first key has been ignored by views,
+            // so it is not replayed.
+            keys.split_off(1)
+        } else {
+            keys
+        }
+    };
+
+    let count = state.count.parse::<usize>().ok();
+    state.clear();
+    Some(EventResult::with_cb(move |s| {
+        replay_events(s, &events, count)
+    }))
+}
+
+/// A callback run after views ignore a key event.
+///
+/// The callback starts a count prefix or a key sequence with the event.
+fn on_post_key_event(e: &Event) -> Option<EventResult> {
+    let mut state = lock_pending_keys();
+    if state.replaying {
+        return None;
+    }
+
+    match e {
+        // `0` only continues a count prefix
+        Event::Char(c) if c.is_ascii_digit() && (*c != '0' || !state.count.is_empty()) => {
+            if state.count.len() < MAX_COUNT_DIGITS {
+                state.count.push(*c);
+            }
+            Some(EventResult::Consumed(None))
+        }
+        _ if is_sequence_prefix(std::slice::from_ref(e)) => {
+            state.keys = vec![e.clone()];
+            Some(EventResult::Consumed(None))
+        }
+        _ => None,
+    }
+}
+
+/// Set up the application's callbacks handling key sequences and count prefixes
+pub fn set_up_key_sequence_callbacks(s: &mut Cursive) {
+    s.set_on_pre_event_inner(EventTrigger::from_fn(is_key_event), on_pre_key_event);
+    s.set_on_event_inner(EventTrigger::from_fn(is_key_event), on_post_key_event);
+}
+
+/// A view displaying the count prefix and the key sequence being typed
+pub struct PendingKeysView;
+
+impl View for PendingKeysView {
+    fn draw(&self, printer: &Printer) {
+        let desc = get_pending_keys_desc();
+        if !desc.is_empty() {
+            printer.with_style(
+                Style::from(config::get_config_theme().component_style.bold),
+                |p| p.print((0, 0), &format!("{desc} ")),
+            );
+        }
+    }
+
+    fn required_size(&mut self, _: Vec2) -> Vec2 {
+        let desc = get_pending_keys_desc();
+        if desc.is_empty() {
+            Vec2::new(0, 1)
+        } else {
+            Vec2::new(desc.width() + 1, 1)
+        }
+    }
+
+    fn needs_relayout(&self) -> bool {
+        true
+    }
+}
@@ -1,4 +1,4 @@
-use super::{help_view::HasHelpView, text_view, utils};
+use super::{command_palette, help_view::HasHelpView, text_view, utils};
 use crate::prelude::*;
 
 type LinkDialogContent = ScrollView<LinearLayout>;
@@ -99,6 +99,15 @@ pub fn get_link_dialog(client: &'static client::HNClient, links: &[String]) -> i
         .on_pre_event(config::get_global_keymap().open_help_dialog.clone(), |s| {
             s.add_layer(LinkDialog::construct_on_event_help_view())
         })
+        .on_pre_event(
+            config::get_global_keymap().open_command_palette.clone(),
+            move |s| {
+                s.add_layer(command_palette::construct_command_palette(
+                    client,
+                    LinkDialog::command_groups(),
+                ))
+            },
+        )
         .max_height(32)
         .max_width(64)
 }
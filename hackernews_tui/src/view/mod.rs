@@ -1,7 +1,12 @@
 mod async_view;
+mod command_palette;
+mod find_bar;
 mod fn_view_wrapper;
+mod key_sequence;
 mod link_dialog;
 mod result_view;
+mod session;
+mod split_view;
 mod text_view;
 mod traits;
 mod utils;
@@ -9,11 +14,14 @@ mod utils;
 pub mod article_view;
 pub mod comment_view;
 pub mod help_view;
+pub mod history_view;
 pub mod search_view;
 pub mod story_view;
 
 use crate::view::help_view::HasHelpView;
 
+pub use session::save_session;
+
 use crate::prelude::*;
 
 fn set_up_switch_story_view_shortcut(
@@ -73,20 +81,34 @@ fn set_up_global_callbacks(s: &mut Cursive, client: &'static client::HNClient) {
         .custom_keymaps
         .iter()
         .for_each(|data| {
-            s.set_on_post_event(data.key.clone(), move |s| {
-                story_view::construct_and_add_new_story_view(
-                    s,
-                    client,
-                    &data.tag,
-                    if data.by_date {
-                        client::StorySortMode::Date
-                    } else {
-                        client::StorySortMode::Points
-                    },
-                    0,
-                    data.numeric_filters,
-                    false,
-                );
+            s.set_on_post_event(data.key.clone(), move |s| match &data.view {
+                config::CustomView::StoryView {
+                    tag,
+                    by_date,
+                    numeric_filters,
+                } => {
+                    story_view::construct_and_add_new_story_view(
+                        s,
+                        client,
+                        tag,
+                        if *by_date {
+                            client::StorySortMode::Date
+                        } else {
+                            client::StorySortMode::Points
+                        },
+                        0,
+                        *numeric_filters,
+                        false,
+                    );
+                }
+                config::CustomView::SavedSearch { saved_search } => {
+                    match crate::storage::get_saved_search(saved_search) {
+                        Some(search) => {
+                            search_view::construct_and_add_new_search_view(s, client, Some(search))
+                        }
+                        None => warn!("failed to find the saved search {saved_search}"),
+                    }
+                }
             });
         });
 
@@ -94,27 +116,63 @@ fn set_up_global_callbacks(s: &mut Cursive, client: &'static client::HNClient) {
     // end of navigation shortcuts for Story Views
     // ............................................
 
-    s.set_on_post_event(global_keymap.goto_previous_view, |s| {
-        if s.screen_mut().len() > 1 {
-            s.pop_layer();
-        }
+    s.set_on_post_event(
+        global_keymap.goto_previous_view,
+        session::goto_previous_view,
+    );
+
+    s.set_on_post_event(global_keymap.goto_next_view, move |s| {
+        session::goto_next_view(s, client);
+    });
+
+    s.set_on_post_event(global_keymap.goto_history_view, move |s| {
+        history_view::construct_and_add_new_history_view(s, client);
+    });
+
+    // tab shortcuts
+    s.set_on_post_event(global_keymap.new_tab, move |s| {
+        session::open_new_tab(s);
+        story_view::construct_and_add_new_story_view(
+            s,
+            client,
+            "front_page",
+            client::StorySortMode::None,
+            0,
+            client::StoryNumericFilters::default(),
+            false,
+        );
     });
+    s.set_on_post_event(global_keymap.close_tab, session::close_current_tab);
+    s.set_on_post_event(global_keymap.next_tab, |s| session::goto_next_tab(s, true));
+    s.set_on_post_event(global_keymap.prev_tab, |s| session::goto_next_tab(s, false));
 
     s.set_on_post_event(global_keymap.goto_search_view, move |s| {
-        search_view::construct_and_add_new_search_view(s, client);
+        search_view::construct_and_add_new_search_view(s, client, None);
     });
 
     s.set_on_post_event(global_keymap.open_help_dialog, |s| {
         s.add_layer(help_view::DefaultHelpView::construct_on_event_help_view())
     });
 
+    s.set_on_post_event(global_keymap.open_command_palette, move |s| {
+        s.add_layer(command_palette::construct_command_palette(
+            client,
+            help_view::DefaultHelpView::command_groups(),
+        ))
+    });
+
     s.set_on_post_event(global_keymap.quit, |s| s.quit());
+
+    key_sequence::set_up_key_sequence_callbacks(s);
 }
 
 /// Initialize the application's UI
+///
+/// If `restore` is `true` and no start id is specified, the views of the last session are rebuilt.
 pub fn init_ui(
     client: &'static client::HNClient,
     start_id: Option<u32>,
+    restore: bool,
 ) -> cursive::CursiveRunnable {
     let mut s = cursive::default();
 
@@ -137,12 +195,15 @@ pub fn init_ui(
         t.palette[PaletteStyle::HighlightInactive] = ColorStyle::highlight_inactive().into();
     });
 
+    let state = session::NavigationState::new(&s);
+    s.set_user_data(state);
     set_up_global_callbacks(&mut s, client);
 
     match start_id {
         Some(id) => {
-            comment_view::construct_and_add_new_comment_view(&mut s, client, id, false);
+            comment_view::construct_and_add_new_comment_view(&mut s, client, id, None, false);
         }
+        None if restore && session::restore_session(&mut s, client) => {}
         None => {
             // render `front_page` story view as the application's startup view if no start id is specified
             story_view::construct_and_add_new_story_view(
@@ -157,5 +218,19 @@ pub fn init_ui(
         }
     }
 
+    // report problems of the keymap configurations such as conflicting or invalid keys
+    let keymap_problems = config::get_config().keymap.validate();
+    if !keymap_problems.is_empty() {
+        let content = keymap_problems
+            .iter()
+            .map(|problem| format!("- {problem}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        s.add_layer(utils::construct_info_dialog(
+            "Keymap problems",
+            format!("The following problems are found in the keymap configurations:\n\n{content}"),
+        ));
+    }
+
     s
 }
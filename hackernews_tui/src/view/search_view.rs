@@ -1,5 +1,18 @@
-use super::{help_view::*, story_view, text_view::EditableTextView, utils};
+use super::{
+    command_palette, comment_view, help_view::*, key_sequence, session, story_view, text_view,
+    text_view::EditableTextView, traits::*, utils,
+};
+use crate::parser::parse_hn_html_text;
 use crate::prelude::*;
+use crate::storage;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+/// the delay before sending a search request after the query is edited,
+/// so that typing a query doesn't result in one request per keystroke
+const SEARCH_DEBOUNCE_DELAY: std::time::Duration = std::time::Duration::from_millis(300);
 
 #[derive(Copy, Clone, PartialEq, Eq)]
 enum SearchViewMode {
@@ -7,23 +20,41 @@ enum SearchViewMode {
     Search,
 }
 
-struct MatchedStories {
-    pub query: String,
-    pub page: usize,
-    pub by_date: bool,
-    pub stories: Vec<Story>,
+/// Items matching a search query
+enum MatchedItems {
+    Stories(Vec<Story>),
+    Texts(Vec<TextMatch>),
+}
+
+struct SearchResult {
+    /// the generation of the search request
+    pub generation: usize,
+    /// matched items, `None` if failed to retrieve the items
+    pub items: Option<MatchedItems>,
 }
 
-/// SearchView is a View used to search stories
+/// SearchView is a View used to search stories or comments
 pub struct SearchView {
     mode: SearchViewMode,
     page: usize,
     by_date: bool,
+    target: client::SearchTarget,
+
+    /// the search history, the most recent query comes first
+    history: Vec<String>,
+    /// the index of the currently selected query in the search history
+    history_id: Option<usize>,
+    /// the query being edited before navigating the search history
+    draft_query: String,
 
     view: LinearLayout,
 
-    sender: std::sync::mpsc::Sender<MatchedStories>,
-    receiver: std::sync::mpsc::Receiver<MatchedStories>,
+    /// the generation of the latest search request, which is increased
+    /// every time a new request is made. Results of outdated requests are dropped.
+    generation: Arc<AtomicUsize>,
+
+    sender: std::sync::mpsc::Sender<SearchResult>,
+    receiver: std::sync::mpsc::Receiver<SearchResult>,
 
     client: &'static client::HNClient,
     cb_sink: CbSink,
@@ -36,13 +67,19 @@ impl SearchView {
 
         let view = LinearLayout::vertical()
             .child(
-                // construct a search bar view consisting of a description and an editable search text views
-                LinearLayout::horizontal()
-                    .child(TextView::new(StyledString::styled(
-                        "Search: ",
-                        config::get_config_theme().component_style.matched_highlight,
-                    )))
-                    .child(EditableTextView::new()),
+                LinearLayout::vertical()
+                    .child(
+                        // construct a search bar view consisting of a description and an editable search text views
+                        LinearLayout::horizontal()
+                            .child(TextView::new(Self::get_search_bar_desc(
+                                client::SearchTarget::Stories,
+                            )))
+                            .child(EditableTextView::new())
+                            // a view indicating whether a search request is in flight
+                            .child(TextView::new("")),
+                    )
+                    // a view displaying the active search filters
+                    .child(HideableView::new(TextView::new("")).hidden()),
             )
             .child(story_view::construct_story_main_view(vec![], client, 0).full_height());
 
@@ -50,7 +87,12 @@ impl SearchView {
             mode: SearchViewMode::Search,
             page: 0,
             by_date: false,
+            target: client::SearchTarget::Stories,
+            history: storage::get_search_history(),
+            history_id: None,
+            draft_query: String::new(),
             view,
+            generation: Arc::new(AtomicUsize::new(0)),
             client,
             cb_sink,
             sender,
@@ -58,86 +100,271 @@ impl SearchView {
         }
     }
 
-    pub fn get_search_text_view_mut(&mut self) -> Option<&mut EditableTextView> {
+    /// constructs new `SearchView` from a saved search
+    pub fn from_saved_search(
+        client: &'static client::HNClient,
+        cb_sink: CbSink,
+        search: storage::SavedSearch,
+    ) -> Self {
+        let mut view = Self::new(client, cb_sink);
+        view.by_date = search.by_date;
+        if view.target != search.target {
+            view.toggle_search_target();
+        }
+        view.set_query(&search.query);
+        view
+    }
+
+    /// gets the information to rebuild the search view in a new session
+    pub fn get_session_view(&mut self) -> storage::SessionView {
+        storage::SessionView::SearchView {
+            query: self.get_query(),
+            by_date: self.by_date,
+            target: self.target,
+        }
+    }
+
+    fn get_query(&mut self) -> String {
+        self.get_search_text_view_mut()
+            .map(|view| view.get_text())
+            .unwrap_or_default()
+    }
+
+    /// sets the current query and retrieves the matched items
+    fn set_query(&mut self, query: &str) {
+        if let Some(view) = self.get_search_text_view_mut() {
+            view.set_text(query);
+        }
+        self.page = 0;
+        self.retrieve_matched_items();
+    }
+
+    /// adds the current query to the search history
+    pub fn update_search_history(&mut self) {
+        storage::add_search_history(&self.get_query());
+        self.history = storage::get_search_history();
+        self.history_id = None;
+    }
+
+    /// replaces the current query with the previous (older) query in the search history
+    pub fn prev_history_entry(&mut self) {
+        let id = match self.history_id {
+            None => {
+                self.draft_query = self.get_query();
+                0
+            }
+            Some(id) => id + 1,
+        };
+        if id < self.history.len() {
+            self.history_id = Some(id);
+            self.set_query(&self.history[id].clone());
+        }
+    }
+
+    /// replaces the current query with the next (newer) query in the search history
+    pub fn next_history_entry(&mut self) {
+        match self.history_id {
+            None => {}
+            Some(0) => {
+                self.history_id = None;
+                self.set_query(&self.draft_query.clone());
+            }
+            Some(id) => {
+                self.history_id = Some(id - 1);
+                self.set_query(&self.history[id - 1].clone());
+            }
+        }
+    }
+
+    /// Get the description of the search bar based on the search target
+    fn get_search_bar_desc(target: client::SearchTarget) -> StyledString {
+        StyledString::styled(
+            match target {
+                client::SearchTarget::Stories => "Search (stories): ",
+                client::SearchTarget::Comments => "Search (comments): ",
+            },
+            config::get_config_theme().component_style.matched_highlight,
+        )
+    }
+
+    /// toggles the search target and updates the search bar accordingly
+    pub fn toggle_search_target(&mut self) {
+        self.target = self.target.toggle();
+        let desc = Self::get_search_bar_desc(self.target);
+        if let Some(view) = self
+            .get_search_bar_mut()
+            .and_then(|v| v.get_child_mut(0))
+            .and_then(|v| v.downcast_mut::<TextView>())
+        {
+            view.set_content(desc);
+        }
+    }
+
+    fn get_search_bar_mut(&mut self) -> Option<&mut LinearLayout> {
         self.view
             .get_child_mut(0)?
             .downcast_mut::<LinearLayout>()?
+            .get_child_mut(0)?
+            .downcast_mut::<LinearLayout>()
+    }
+
+    pub fn get_search_text_view_mut(&mut self) -> Option<&mut EditableTextView> {
+        self.get_search_bar_mut()?
             .get_child_mut(1)?
             .downcast_mut::<EditableTextView>()
     }
 
-    /// retrieves stories matching the current query by making an external (API) request
+    /// updates the view displaying the active search filters
+    fn update_filters_view(&mut self, filters: &client::SearchFilters) {
+        let desc = filters.desc(self.target);
+        if let Some(view) = self
+            .view
+            .get_child_mut(0)
+            .and_then(|v| v.downcast_mut::<LinearLayout>())
+            .and_then(|v| v.get_child_mut(1))
+            .and_then(|v| v.downcast_mut::<HideableView<TextView>>())
+        {
+            view.set_visible(!desc.is_empty());
+            view.get_inner_mut().set_content(StyledString::styled(
+                format!("Filters: {desc}"),
+                config::get_config_theme().component_style.metadata,
+            ));
+        }
+    }
+
+    /// updates the view indicating whether a search request is in flight
+    fn update_in_flight_view(&mut self, in_flight: bool) {
+        if let Some(view) = self
+            .get_search_bar_mut()
+            .and_then(|v| v.get_child_mut(2))
+            .and_then(|v| v.downcast_mut::<TextView>())
+        {
+            if in_flight {
+                view.set_content(StyledString::styled(
+                    " (searching...)",
+                    config::get_config_theme().component_style.metadata,
+                ));
+            } else {
+                view.set_content("");
+            }
+        }
+    }
+
+    /// retrieves items matching the current query by making an external (API) request
+    pub fn retrieve_matched_items(&mut self) {
+        self.send_search_request(None);
+    }
+
+    /// similar to `retrieve_matched_items` but only makes the request if
+    /// the search state isn't changed during a short delay
+    pub fn retrieve_matched_items_debounced(&mut self) {
+        self.send_search_request(Some(SEARCH_DEBOUNCE_DELAY));
+    }
+
+    /// sends a search request based on the current search state in a separate thread
     ///
     /// To ensure this function not blocking, message passing with channels is used.
-    pub fn retrieve_matched_stories(&mut self) {
+    /// Each request is assigned a new generation. A request is cancelled if a newer request
+    /// is made before it is sent, and its result is dropped if it arrives after a newer request is made.
+    fn send_search_request(&mut self, delay: Option<std::time::Duration>) {
         let query = match self.get_search_text_view_mut() {
             None => return,
             Some(view) => view.get_text(),
         };
 
+        let search_query = client::SearchQuery::parse(&query);
+        self.update_filters_view(&search_query.filters);
+        self.update_in_flight_view(true);
+
+        let generation = self.generation.fetch_add(1, Ordering::SeqCst) + 1;
+
         std::thread::spawn({
+            let latest_generation = self.generation.clone();
             let sender = self.sender.clone();
             let client = self.client.clone();
             let by_date = self.by_date;
             let page = self.page;
+            let target = self.target;
 
             // use a `cb_sink` to notify the `Cursive` renderer to re-draw the application
-            // after successfully retrieving matched stories
+            // after successfully retrieving matched items
             let cb_sink = self.cb_sink.clone();
 
-            move || match client.get_matched_stories(&query, by_date, page) {
-                Ok(stories) => {
-                    sender
-                        .send(MatchedStories {
-                            query,
-                            stories,
-                            by_date,
-                            page,
-                        })
-                        .unwrap();
-                    // send a dummy callback to `cb_sink`
-                    cb_sink.send(Box::new(move |_| {})).unwrap();
+            move || {
+                let is_outdated = || latest_generation.load(Ordering::SeqCst) != generation;
+
+                if let Some(delay) = delay {
+                    std::thread::sleep(delay);
+                }
+                if is_outdated() {
+                    return;
+                }
+
+                let items = match target {
+                    client::SearchTarget::Stories => client
+                        .get_matched_stories(&search_query, by_date, page)
+                        .map(MatchedItems::Stories),
+                    client::SearchTarget::Comments => client
+                        .get_matched_texts(&search_query, by_date, page)
+                        .map(MatchedItems::Texts),
+                };
+                if is_outdated() {
+                    return;
                 }
-                Err(err) => {
-                    warn!(
-                        "failed to get matched stories (query={}, by_date={}, page={}): {}",
-                        query, by_date, page, err
-                    );
+
+                let items = match items {
+                    Ok(items) => Some(items),
+                    Err(err) => {
+                        warn!(
+                            "failed to get matched items (query={}, by_date={}, page={}, target={:?}): {}",
+                            query, by_date, page, target, err
+                        );
+                        None
+                    }
+                };
+                // the view may have been closed while the request was in flight,
+                // so the sending errors are ignored
+                if sender.send(SearchResult { generation, items }).is_ok() {
+                    // send a dummy callback to `cb_sink`
+                    cb_sink.send(Box::new(move |_| {})).unwrap_or_default();
                 }
             }
         });
     }
 
-    /// tries to update the Story View representing matched stories based on
+    /// tries to update the view representing matched items based on
     /// the results from previous query requests
     pub fn try_update_view(&mut self) {
-        let query = match self.get_search_text_view_mut() {
-            None => return,
-            Some(view) => view.get_text(),
-        };
-        while let Ok(matched_stories) = self.receiver.try_recv() {
-            // got a `matched_stories` result but only care about the one matching current state
-            if query == matched_stories.query
-                && self.page == matched_stories.page
-                && self.by_date == matched_stories.by_date
-            {
-                self.update_stories_view(matched_stories.stories);
+        while let Ok(result) = self.receiver.try_recv() {
+            // got a search result but only care about the one from the latest request
+            if result.generation != self.generation.load(Ordering::SeqCst) {
+                continue;
+            }
+            self.update_in_flight_view(false);
+            if let Some(items) = result.items {
+                self.update_matched_items_view(items);
             }
         }
     }
 
-    /// updates the Story View with new matched stories
-    fn update_stories_view(&mut self, stories: Vec<Story>) {
+    /// updates the view with new matched items
+    fn update_matched_items_view(&mut self, items: MatchedItems) {
         self.view.remove_child(1);
-        let starting_id = client::SEARCH_LIMIT * self.page;
-        self.view.add_child(
-            story_view::construct_story_main_view(stories, self.client, starting_id).full_height(),
-        );
-        // the old Story View is deleted hence losing the current focus,
-        // we need to place the focus back to the new Story View
+        let starting_id = self.client.search_limit() * self.page;
+        match items {
+            MatchedItems::Stories(stories) => self.view.add_child(
+                story_view::construct_story_main_view(stories, self.client, starting_id)
+                    .full_height(),
+            ),
+            MatchedItems::Texts(matches) => self.view.add_child(
+                construct_text_match_main_view(matches, self.client, starting_id).full_height(),
+            ),
+        }
+        // the old view is deleted hence losing the current focus,
+        // we need to place the focus back to the new view
         if self.mode == SearchViewMode::Navigation {
             self.view.set_focus_index(1).unwrap_or_else(|_| {
-                // no Story View to focus on, or no stories to display,
+                // no view to focus on, or no items to display,
                 // change back to Search mode
                 self.mode = SearchViewMode::Search;
                 EventResult::Ignored
@@ -155,11 +382,173 @@ impl ViewWrapper for SearchView {
     }
 }
 
-fn construct_search_main_view(client: &'static client::HNClient, cb_sink: CbSink) -> impl View {
+/// TextMatchView is a View displaying a list of comments (or stories' text) matching a search query
+pub struct TextMatchView {
+    matches: Vec<TextMatch>,
+
+    view: ScrollView<LinearLayout>,
+}
+
+impl ViewWrapper for TextMatchView {
+    wrap_impl!(self.view: ScrollView<LinearLayout>);
+}
+
+impl TextMatchView {
+    pub fn new(matches: Vec<TextMatch>, starting_id: usize) -> Self {
+        // hide or dim matched items satisfying the filtering rules
+        let dim_filtered_items = config::get_config().filter.dim_filtered_items;
+        let matches = matches
+            .into_iter()
+            .filter(|m| dim_filtered_items || !m.is_filtered())
+            .collect::<Vec<_>>();
+
+        let view = LinearLayout::vertical()
+            .with(|s| {
+                matches.iter().enumerate().for_each(|(i, m)| {
+                    let mut text = Self::get_match_text(starting_id + i + 1, m);
+                    if m.is_filtered() {
+                        text = StyledString::styled(
+                            text.source(),
+                            config::get_config_theme().component_style.filtered_item,
+                        );
+                    }
+                    s.add_child(PaddedView::lrtb(0, 0, 0, 1, text_view::TextView::new(text)));
+                })
+            })
+            .scrollable();
+
+        TextMatchView { matches, view }
+    }
+
+    /// Get the text displaying a matched item, its author and the title of its parent story
+    fn get_match_text(id: usize, m: &TextMatch) -> StyledString {
+        let component_style = &config::get_config_theme().component_style;
+
+        let mut text = StyledString::styled(format!("{id}. "), component_style.metadata);
+        text.append_styled(&m.story_title, component_style.bold);
+        text.append_plain("\n");
+        text.append_styled(&m.author, component_style.username);
+        text.append_styled(
+            format!(" {} ago\n", crate::utils::get_elapsed_time_as_text(m.time)),
+            component_style.metadata,
+        );
+        text.append(parse_hn_html_text(m.content.clone(), Style::default(), 0).content);
+        text
+    }
+
+    inner_getters!(self.view: ScrollView<LinearLayout>);
+}
+
+impl ListViewContainer for TextMatchView {
+    fn get_inner_list(&self) -> &LinearLayout {
+        self.get_inner().get_inner()
+    }
+
+    fn get_inner_list_mut(&mut self) -> &mut LinearLayout {
+        self.get_inner_mut().get_inner_mut()
+    }
+
+    fn on_set_focus_index(&mut self, old_id: usize, new_id: usize) {
+        let direction = old_id <= new_id;
+
+        // enable auto-scrolling when changing the focused index of the view
+        self.scroll(direction);
+    }
+}
+
+impl ScrollViewContainer for TextMatchView {
+    type ScrollInner = LinearLayout;
+
+    fn get_inner_scroll_view(&self) -> &ScrollView<LinearLayout> {
+        self.get_inner()
+    }
+
+    fn get_inner_scroll_view_mut(&mut self) -> &mut ScrollView<LinearLayout> {
+        self.get_inner_mut()
+    }
+}
+
+/// Construct a view displaying a list of matched texts.
+///
+/// The view re-uses the story view's keymaps to navigate between matches.
+fn construct_text_match_main_view(
+    matches: Vec<TextMatch>,
+    client: &'static client::HNClient,
+    starting_id: usize,
+) -> OnEventView<TextMatchView> {
+    let story_view_keymap = config::get_story_view_keymap().clone();
+
+    let goto_match_comment_view = move |s: &mut TextMatchView| {
+        let m = s.matches.get(s.get_focus_index())?;
+        let (story_id, item_id) = (m.story_id, m.id);
+        Some(EventResult::with_cb(move |s| {
+            comment_view::construct_and_add_new_comment_view(
+                s,
+                client,
+                story_id,
+                Some(item_id),
+                false,
+            )
+        }))
+    };
+
+    OnEventView::new(TextMatchView::new(matches, starting_id))
+        // don't allow the inner `LinearLayout` child view to handle the event
+        // because of its pre-defined `on_event` function
+        .on_pre_event_inner(EventTrigger::from_fn(|_| true), |_, _| {
+            Some(EventResult::Ignored)
+        })
+        .on_pre_event_inner(story_view_keymap.prev_story, |s, _| {
+            let id = s.get_focus_index();
+            if id == 0 {
+                None
+            } else {
+                s.set_focus_index(id - 1)
+            }
+        })
+        .on_pre_event_inner(story_view_keymap.next_story, |s, _| {
+            let id = s.get_focus_index();
+            s.set_focus_index(id + 1)
+        })
+        .on_pre_event_inner(story_view_keymap.goto_story_comment_view, move |s, _| {
+            goto_match_comment_view(s)
+        })
+        .on_pre_event_inner(story_view_keymap.open_story_in_browser, |s, _| {
+            let id = s.matches.get(s.get_focus_index())?.id;
+            utils::open_url_in_browser(&format!("{}/item?id={}", client::HN_HOST_URL, id));
+            Some(EventResult::Consumed(None))
+        })
+        .on_pre_event_inner(story_view_keymap.goto_story, move |s, _| {
+            let number = key_sequence::get_count()?;
+            if number < starting_id + 1 {
+                return None;
+            }
+            let number = number - 1 - starting_id;
+            if number < s.len() {
+                s.set_focus_index(number).unwrap();
+                Some(EventResult::Consumed(None))
+            } else {
+                None
+            }
+        })
+        .on_mouse_events(goto_match_comment_view)
+        .on_scroll_events()
+}
+
+fn construct_search_main_view(
+    client: &'static client::HNClient,
+    cb_sink: CbSink,
+    search: Option<storage::SavedSearch>,
+) -> impl View {
     let story_view_keymap = config::get_story_view_keymap().clone();
     let search_view_keymap = config::get_search_view_keymap().clone();
 
-    OnEventView::new(SearchView::new(client, cb_sink))
+    let view = match search {
+        Some(search) => SearchView::from_saved_search(client, cb_sink, search),
+        None => SearchView::new(client, cb_sink),
+    };
+
+    OnEventView::new(view)
         .on_pre_event_inner(EventTrigger::from_fn(|_| true), |s, e| match s.mode {
             SearchViewMode::Navigation => None,
             SearchViewMode::Search => {
@@ -168,7 +557,8 @@ fn construct_search_main_view(client: &'static client::HNClient, cb_sink: CbSink
                     Event::Char(c) => {
                         view.add_char(c);
                         s.page = 0;
-                        s.retrieve_matched_stories();
+                        s.history_id = None;
+                        s.retrieve_matched_items_debounced();
                     }
                     _ => {
                         // handle editing shortcuts when in the search mode
@@ -176,7 +566,12 @@ fn construct_search_main_view(client: &'static client::HNClient, cb_sink: CbSink
                         if edit_keymap.backward_delete_char.has_event(e) {
                             view.del_char();
                             s.page = 0;
-                            s.retrieve_matched_stories();
+                            s.history_id = None;
+                            s.retrieve_matched_items_debounced();
+                        } else if edit_keymap.prev_history_entry.has_event(e) {
+                            s.prev_history_entry();
+                        } else if edit_keymap.next_history_entry.has_event(e) {
+                            s.next_history_entry();
                         } else if edit_keymap.move_cursor_left.has_event(e) {
                             view.move_cursor_left();
                         } else if edit_keymap.move_cursor_right.has_event(e) {
@@ -196,6 +591,7 @@ fn construct_search_main_view(client: &'static client::HNClient, cb_sink: CbSink
         .on_pre_event_inner(search_view_keymap.to_navigation_mode, |s, _| match s.mode {
             SearchViewMode::Navigation => None,
             SearchViewMode::Search => {
+                s.update_search_history();
                 if s.view.set_focus_index(1).is_ok() {
                     s.mode = SearchViewMode::Navigation;
                 }
@@ -211,13 +607,26 @@ fn construct_search_main_view(client: &'static client::HNClient, cb_sink: CbSink
                 Some(EventResult::Consumed(None))
             }
         })
+        .on_pre_event_inner(search_view_keymap.toggle_search_target, |s, _| {
+            s.page = 0;
+            s.toggle_search_target();
+            s.retrieve_matched_items();
+            Some(EventResult::Consumed(None))
+        })
+        .on_pre_event_inner(search_view_keymap.save_search, |s, _| {
+            let query = s.get_query();
+            let (by_date, target) = (s.by_date, s.target);
+            Some(EventResult::with_cb(move |s| {
+                s.add_layer(construct_save_search_dialog(query.clone(), by_date, target))
+            }))
+        })
         // paging/filtering commands while in NavigationMode
         // Those commands need to be handled differently from the story view.
         .on_pre_event_inner(story_view_keymap.cycle_sort_mode, |s, _| match s.mode {
             SearchViewMode::Navigation => {
                 s.page = 0;
                 s.by_date = !s.by_date;
-                s.retrieve_matched_stories();
+                s.retrieve_matched_items();
                 Some(EventResult::Consumed(None))
             }
             SearchViewMode::Search => Some(EventResult::Ignored),
@@ -225,7 +634,7 @@ fn construct_search_main_view(client: &'static client::HNClient, cb_sink: CbSink
         .on_pre_event_inner(story_view_keymap.next_page, |s, _| match s.mode {
             SearchViewMode::Navigation => {
                 s.page += 1;
-                s.retrieve_matched_stories();
+                s.retrieve_matched_items();
                 Some(EventResult::Consumed(None))
             }
             SearchViewMode::Search => Some(EventResult::Ignored),
@@ -234,7 +643,7 @@ fn construct_search_main_view(client: &'static client::HNClient, cb_sink: CbSink
             SearchViewMode::Navigation => {
                 if s.page > 0 {
                     s.page -= 1;
-                    s.retrieve_matched_stories();
+                    s.retrieve_matched_items();
                 }
                 Some(EventResult::Consumed(None))
             }
@@ -243,11 +652,54 @@ fn construct_search_main_view(client: &'static client::HNClient, cb_sink: CbSink
         .on_pre_event(config::get_global_keymap().open_help_dialog.clone(), |s| {
             s.add_layer(SearchView::construct_on_event_help_view());
         })
+        .on_pre_event_inner(
+            config::get_global_keymap().open_command_palette.clone(),
+            move |s, _| match s.mode {
+                SearchViewMode::Navigation => Some(EventResult::with_cb(move |s| {
+                    s.add_layer(command_palette::construct_command_palette(
+                        client,
+                        SearchView::command_groups(),
+                    ))
+                })),
+                SearchViewMode::Search => None,
+            },
+        )
 }
 
-fn construct_search_view(client: &'static client::HNClient, cb_sink: CbSink) -> impl View {
-    let main_view = construct_search_main_view(client, cb_sink);
+/// Construct a dialog to save a search under a name
+fn construct_save_search_dialog(
+    query: String,
+    by_date: bool,
+    target: client::SearchTarget,
+) -> impl View {
+    let title = format!("Save search \"{query}\" as");
+    let edit_view = EditView::new().on_submit(move |s, name| {
+        let name = name.trim();
+        if !name.is_empty() {
+            storage::save_search(storage::SavedSearch {
+                name: name.to_string(),
+                query: query.clone(),
+                by_date,
+                target,
+            });
+        }
+        s.pop_layer();
+    });
+
+    OnEventView::new(Dialog::around(edit_view.fixed_width(32)).title(title)).on_pre_event(
+        config::get_global_keymap().close_dialog.clone(),
+        |s| {
+            s.pop_layer();
+        },
+    )
+}
 
+fn construct_search_view(
+    client: &'static client::HNClient,
+    cb_sink: CbSink,
+    search: Option<storage::SavedSearch>,
+) -> impl View {
+    let main_view = construct_search_main_view(client, cb_sink, search);
     let mut view = LinearLayout::vertical()
         .child(utils::construct_view_title_bar("Search View"))
         .child(main_view)
@@ -259,8 +711,26 @@ fn construct_search_view(client: &'static client::HNClient, cb_sink: CbSink) ->
     view
 }
 
-pub fn construct_and_add_new_search_view(s: &mut Cursive, client: &'static client::HNClient) {
+/// Construct a search view and add it to the screen.
+///
+/// If `search` is specified, the search view is initialized with the saved search.
+pub fn construct_and_add_new_search_view(
+    s: &mut Cursive,
+    client: &'static client::HNClient,
+    search: Option<storage::SavedSearch>,
+) {
     let cb_sink = s.cb_sink().clone();
-    s.screen_mut()
-        .add_transparent_layer(Layer::new(construct_search_view(client, cb_sink)));
+    let session_view = storage::SessionView::SearchView {
+        query: search.as_ref().map(|s| s.query.clone()).unwrap_or_default(),
+        by_date: search.as_ref().is_some_and(|s| s.by_date),
+        target: search
+            .as_ref()
+            .map(|s| s.target)
+            .unwrap_or(client::SearchTarget::Stories),
+    };
+    session::add_session_layer(
+        s,
+        session_view,
+        Layer::new(construct_search_view(client, cb_sink, search)),
+    );
 }
@@ -0,0 +1,485 @@
+use super::{article_view, command_palette, comment_view, search_view, story_view};
+use crate::prelude::*;
+use crate::storage::{self, SessionView};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use unicode_width::UnicodeWidthStr;
+
+/// the tab bar displayed by the session layers, which is updated in `update_tab_bar`
+static TAB_BAR: Mutex<TabBar> = Mutex::new(TabBar::new());
+
+/// TabBar displays the opened tabs of the application
+struct TabBar {
+    /// the screen and the label of each tab, ordered by the tabs' positions
+    tabs: Vec<(ScreenId, String)>,
+    active_screen: ScreenId,
+}
+
+impl TabBar {
+    const fn new() -> Self {
+        Self {
+            tabs: Vec::new(),
+            active_screen: 0,
+        }
+    }
+
+    /// the tab bar is hidden if there is only one opened tab
+    fn is_visible(&self) -> bool {
+        self.tabs.len() > 1
+    }
+
+    fn draw(&self, printer: &Printer) {
+        let component_style = &config::get_config_theme().component_style;
+        let style = Style::from(component_style.title_bar);
+        printer.with_style(style, |p| p.print_hline((0, 0), p.size.x, " "));
+
+        let mut x = 0;
+        for (screen, label) in &self.tabs {
+            let style = if *screen == self.active_screen {
+                style.combine(component_style.current_story_tag)
+            } else {
+                style
+            };
+            printer.with_style(style, |p| p.print((x, 0), label));
+            x += label.width();
+        }
+    }
+
+    /// gets the screen of the tab at the `x` position of the tab bar
+    fn get_screen_at(&self, x: usize) -> Option<ScreenId> {
+        let mut end = 0;
+        self.tabs.iter().find_map(|(screen, label)| {
+            end += label.width();
+            (x < end).then_some(*screen)
+        })
+    }
+}
+
+/// SessionLayer is a View wrapping a layer of the application's screen stack.
+///
+/// It stores the information needed to rebuild the layer in a new session
+/// and displays the tab bar above the wrapped view.
+pub struct SessionLayer {
+    view: BoxedView,
+    session_view: SessionView,
+    /// the height of the tab bar, which is `0` if the tab bar is hidden
+    tab_bar_height: usize,
+}
+
+impl SessionLayer {
+    pub fn new<V: IntoBoxedView>(session_view: SessionView, view: V) -> Self {
+        Self {
+            view: BoxedView::boxed(view),
+            session_view,
+            tab_bar_height: 0,
+        }
+    }
+
+    fn tab_bar_offset(&self) -> Vec2 {
+        Vec2::new(0, self.tab_bar_height)
+    }
+
+    /// gets the information to rebuild the layer
+    fn get_session_view(&mut self) -> SessionView {
+        // the query of a search view can be updated after the view is constructed
+        if let SessionView::SearchView { .. } = self.session_view {
+            if let Some(view) = self
+                .view
+                .downcast_mut::<Layer<LinearLayout>>()
+                .and_then(|v| v.get_inner_mut().get_child_mut(1))
+                .and_then(|v| v.downcast_mut::<OnEventView<search_view::SearchView>>())
+            {
+                return view.get_inner_mut().get_session_view();
+            }
+        }
+        self.session_view.clone()
+    }
+}
+
+impl ViewWrapper for SessionLayer {
+    wrap_impl!(self.view: BoxedView);
+
+    fn wrap_draw(&self, printer: &Printer) {
+        if self.tab_bar_height > 0 {
+            TAB_BAR
+                .lock()
+                .unwrap()
+                .draw(&printer.cropped((printer.size.x, self.tab_bar_height)));
+        }
+        self.view.draw(&printer.offset(self.tab_bar_offset()));
+    }
+
+    fn wrap_required_size(&mut self, req: Vec2) -> Vec2 {
+        let offset = self.tab_bar_offset();
+        self.view.required_size(req.saturating_sub(offset)) + offset
+    }
+
+    fn wrap_layout(&mut self, size: Vec2) {
+        self.tab_bar_height = if TAB_BAR.lock().unwrap().is_visible() {
+            1
+        } else {
+            0
+        };
+        self.view.layout(size.saturating_sub(self.tab_bar_offset()));
+    }
+
+    fn wrap_on_event(&mut self, e: Event) -> EventResult {
+        // clicking a tab in the tab bar switches to the tab
+        if let Event::Mouse {
+            offset,
+            position,
+            event: MouseEvent::Press(MouseButton::Left),
+        } = e
+        {
+            if self.tab_bar_height > 0 && position.y == offset.y {
+                let screen = position
+                    .x
+                    .checked_sub(offset.x)
+                    .and_then(|x| TAB_BAR.lock().unwrap().get_screen_at(x));
+                return match screen {
+                    Some(screen) => EventResult::with_cb(move |s| switch_to_tab(s, screen)),
+                    None => EventResult::Consumed(None),
+                };
+            }
+        }
+        self.view.on_event(e.relativized(self.tab_bar_offset()))
+    }
+
+    fn wrap_important_area(&self, size: Vec2) -> Rect {
+        let offset = self.tab_bar_offset();
+        self.view.important_area(size.saturating_sub(offset)) + offset
+    }
+}
+
+/// The navigation state of the application, which is stored as `Cursive`'s user data.
+///
+/// Each tab of the application is a `Cursive` screen with its own layer stack.
+pub struct NavigationState {
+    /// views closed by going back in each tab, the most recently closed view comes last
+    forward_views: HashMap<ScreenId, Vec<SessionView>>,
+    /// screens of the opened tabs, ordered by their positions in the tab bar
+    tabs: Vec<ScreenId>,
+    /// screens of the closed tabs, which are re-used when opening new tabs
+    closed_screens: Vec<ScreenId>,
+    /// views in each tab's screen stack, which are updated when the tab bar is updated
+    /// or when switching to another tab, so other tabs can be displayed and saved
+    /// without switching to their screens
+    tab_views: HashMap<ScreenId, Vec<SessionView>>,
+    /// whether the views of the last session are being rebuilt,
+    /// the rebuilt views are not added to the navigation history
+    restoring_session: bool,
+}
+
+impl NavigationState {
+    pub fn new(s: &Cursive) -> Self {
+        Self {
+            forward_views: HashMap::new(),
+            tabs: vec![s.active_screen()],
+            closed_screens: vec![],
+            tab_views: HashMap::new(),
+            restoring_session: false,
+        }
+    }
+}
+
+/// Add a layer to the screen stack of the current tab.
+///
+/// Opening a new view clears the views to go forward to.
+pub fn add_session_layer<V: IntoBoxedView>(s: &mut Cursive, session_view: SessionView, view: V) {
+    let screen = s.active_screen();
+    s.with_user_data(|state: &mut NavigationState| state.forward_views.remove(&screen));
+    s.screen_mut()
+        .add_transparent_layer(SessionLayer::new(session_view, view));
+    update_tab_bar(s);
+}
+
+/// Go back to the previous view by closing the top layer
+pub fn goto_previous_view(s: &mut Cursive) {
+    if s.screen_mut().len() <= 1 {
+        return;
+    }
+    let screen = s.active_screen();
+    if let Some(mut layer) = s.pop_layer() {
+        if let Some(layer) = layer.downcast_mut::<SessionLayer>() {
+            let view = layer.get_session_view();
+            s.with_user_data(|state: &mut NavigationState| {
+                state.forward_views.entry(screen).or_default().push(view)
+            });
+        }
+    }
+    update_tab_bar(s);
+}
+
+/// Go forward to the view most recently closed by going back
+pub fn goto_next_view(s: &mut Cursive, client: &'static client::HNClient) {
+    let screen = s.active_screen();
+    let Some(mut forward_views) = s
+        .with_user_data(|state: &mut NavigationState| state.forward_views.remove(&screen))
+        .flatten()
+    else {
+        return;
+    };
+    if let Some(view) = forward_views.pop() {
+        construct_and_add_session_view(s, client, view);
+    }
+    s.with_user_data(|state: &mut NavigationState| {
+        state.forward_views.insert(screen, forward_views)
+    });
+}
+
+/// Open a new tab next to the current tab and switch to it.
+///
+/// The new tab is empty, so a view should be added to it right after.
+pub fn open_new_tab(s: &mut Cursive) {
+    let current = s.active_screen();
+    let closed_screen = s
+        .with_user_data(|state: &mut NavigationState| state.closed_screens.pop())
+        .flatten();
+    let screen = closed_screen.unwrap_or_else(|| s.add_screen());
+    s.with_user_data(|state: &mut NavigationState| {
+        let pos = state.tabs.iter().position(|&t| t == current).unwrap_or(0);
+        state.tabs.insert(pos + 1, screen);
+    });
+    switch_to_tab(s, screen);
+}
+
+/// Close the current tab and switch to the next tab.
+///
+/// The last opened tab cannot be closed.
+pub fn close_current_tab(s: &mut Cursive) {
+    let screen = s.active_screen();
+    let Some(next_screen) = s
+        .with_user_data(|state: &mut NavigationState| {
+            if state.tabs.len() <= 1 {
+                return None;
+            }
+            let pos = state.tabs.iter().position(|&t| t == screen)?;
+            state.tabs.remove(pos);
+            state.forward_views.remove(&screen);
+            state.tab_views.remove(&screen);
+            state.closed_screens.push(screen);
+            Some(state.tabs[pos.min(state.tabs.len() - 1)])
+        })
+        .flatten()
+    else {
+        return;
+    };
+
+    // remove the closed tab's views
+    while !s.screen().is_empty() {
+        s.pop_layer();
+    }
+    s.set_screen(next_screen);
+    update_tab_bar(s);
+}
+
+/// Switch to the next (or previous) tab
+pub fn goto_next_tab(s: &mut Cursive, forward: bool) {
+    let screen = s.active_screen();
+    let Some(next_screen) = s
+        .with_user_data(|state: &mut NavigationState| {
+            let n = state.tabs.len();
+            let pos = state.tabs.iter().position(|&t| t == screen)?;
+            Some(if forward {
+                state.tabs[(pos + 1) % n]
+            } else {
+                state.tabs[(pos + n - 1) % n]
+            })
+        })
+        .flatten()
+    else {
+        return;
+    };
+    switch_to_tab(s, next_screen);
+}
+
+/// Switch to the tab of a screen, storing the views of the current tab
+fn switch_to_tab(s: &mut Cursive, screen: ScreenId) {
+    update_tab_views(s);
+    s.set_screen(screen);
+    update_tab_bar(s);
+}
+
+/// Check if the views of the last session are being rebuilt
+pub fn is_restoring_session(s: &mut Cursive) -> bool {
+    s.with_user_data(|state: &mut NavigationState| state.restoring_session)
+        .unwrap_or_default()
+}
+
+/// Store the views in the current tab's screen stack
+fn update_tab_views(s: &mut Cursive) {
+    let screen = s.active_screen();
+    let views = get_screen_session_views(s);
+    s.with_user_data(|state: &mut NavigationState| state.tab_views.insert(screen, views));
+}
+
+/// Get the views in each opened tab, ordered by the tabs' positions in the tab bar
+fn get_tab_views(s: &mut Cursive) -> Vec<(ScreenId, Vec<SessionView>)> {
+    update_tab_views(s);
+    let active_screen = s.active_screen();
+    s.with_user_data(|state: &mut NavigationState| {
+        state
+            .tabs
+            .iter()
+            .map(|&screen| {
+                let views = state.tab_views.get(&screen).cloned().unwrap_or_default();
+                (screen, views)
+            })
+            .collect()
+    })
+    .unwrap_or_else(|| vec![(active_screen, get_screen_session_views(s))])
+}
+
+/// Get the information to rebuild the views in the active screen
+fn get_screen_session_views(s: &mut Cursive) -> Vec<SessionView> {
+    let screen = s.screen_mut();
+    (0..screen.len())
+        .filter_map(|i| {
+            screen
+                .get_mut(LayerPosition::FromBack(i))
+                .and_then(|v| v.downcast_mut::<SessionLayer>())
+                .map(|v| v.get_session_view())
+        })
+        .collect()
+}
+
+/// Get a short label describing a view, which is displayed in the tab bar
+fn get_tab_label(view: Option<&SessionView>) -> String {
+    match view {
+        Some(SessionView::StoryView { tag, .. }) => tag.clone(),
+        Some(SessionView::CommentView { item_id }) => format!("thread {item_id}"),
+        Some(SessionView::ArticleView { url }) => {
+            crate::utils::get_url_domain(url).unwrap_or_else(|| "article".to_string())
+        }
+        Some(SessionView::SearchView { .. }) => "search".to_string(),
+        Some(SessionView::UserView { username }) => format!("user {username}"),
+        None => "empty".to_string(),
+    }
+}
+
+/// Update the tab bar displaying the opened tabs.
+///
+/// The tab bar is hidden if there is only one opened tab.
+pub fn update_tab_bar(s: &mut Cursive) {
+    let tabs = get_tab_views(s)
+        .into_iter()
+        .enumerate()
+        .map(|(i, (screen, views))| {
+            let label = format!(" {}:{} ", i + 1, get_tab_label(views.last()));
+            (screen, label)
+        })
+        .collect();
+
+    let mut tab_bar = TAB_BAR.lock().unwrap();
+    tab_bar.tabs = tabs;
+    tab_bar.active_screen = s.active_screen();
+}
+
+/// Rebuild a view from its session information and add it to the screen stack
+pub fn construct_and_add_session_view(
+    s: &mut Cursive,
+    client: &'static client::HNClient,
+    view: SessionView,
+) {
+    match view {
+        SessionView::StoryView {
+            tag,
+            sort_mode,
+            page,
+            numeric_filters,
+        } => match story_view::get_story_tag(&tag) {
+            Some(tag) => story_view::construct_and_add_new_story_view(
+                s,
+                client,
+                tag,
+                sort_mode,
+                page,
+                numeric_filters,
+                false,
+            ),
+            None => warn!("unknown story tag {tag}"),
+        },
+        SessionView::CommentView { item_id } => {
+            // the focused comment is restored from the thread's saved state
+            comment_view::construct_and_add_new_comment_view(s, client, item_id, None, false);
+        }
+        SessionView::ArticleView { url } => {
+            article_view::construct_and_add_new_article_view(client, s, &url);
+        }
+        SessionView::SearchView {
+            query,
+            by_date,
+            target,
+        } => {
+            let search = if query.is_empty() {
+                None
+            } else {
+                Some(storage::SavedSearch {
+                    name: String::new(),
+                    query,
+                    by_date,
+                    target,
+                })
+            };
+            search_view::construct_and_add_new_search_view(s, client, search);
+        }
+        SessionView::UserView { username } => {
+            command_palette::add_user_dialog(s, client, username);
+        }
+    }
+}
+
+/// Save the views in the application's tabs. Dialogs and other layers
+/// that are not `SessionLayer` are skipped.
+pub fn save_session(s: &mut Cursive) {
+    let tabs = get_tab_views(s);
+    let active_screen = s.active_screen();
+
+    let session = storage::Session {
+        active_tab: tabs
+            .iter()
+            .position(|(t, _)| *t == active_screen)
+            .unwrap_or_default(),
+        tabs: tabs.into_iter().map(|(_, views)| views).collect(),
+    };
+
+    storage::save_session(&session);
+}
+
+/// Rebuild the tabs and views of the last session.
+///
+/// Returns `false` if there is no view to rebuild.
+pub fn restore_session(s: &mut Cursive, client: &'static client::HNClient) -> bool {
+    let session = storage::get_session();
+    let tabs = session
+        .tabs
+        .into_iter()
+        .filter(|views| !views.is_empty())
+        .collect::<Vec<_>>();
+    if tabs.is_empty() {
+        return false;
+    }
+
+    s.with_user_data(|state: &mut NavigationState| state.restoring_session = true);
+    let mut screens = vec![];
+    for (i, views) in tabs.into_iter().enumerate() {
+        if i > 0 {
+            open_new_tab(s);
+        }
+        screens.push(s.active_screen());
+        for view in views {
+            construct_and_add_session_view(s, client, view);
+        }
+    }
+    // the restored views are not opened by going forward
+    s.with_user_data(|state: &mut NavigationState| {
+        state.forward_views.clear();
+        state.restoring_session = false;
+    });
+
+    match screens.get(session.active_tab) {
+        Some(&screen) => switch_to_tab(s, screen),
+        None => update_tab_bar(s),
+    }
+    true
+}
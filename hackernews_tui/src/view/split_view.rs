@@ -0,0 +1,304 @@
+use super::{
+    article_view, comment_view, result_view::ResultView, story_view::StoryView, traits::*,
+};
+use crate::prelude::*;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+/// the delay before loading the preview of the focused story,
+/// so that moving the focus quickly between stories doesn't make unnecessary requests
+const PREVIEW_DEBOUNCE_DELAY: std::time::Duration = std::time::Duration::from_millis(300);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PreviewMode {
+    Comments,
+    Article,
+}
+
+enum PreviewContent {
+    Comments(Box<PageData>),
+    Article(Article),
+}
+
+struct PreviewResult {
+    /// the generation of the preview request
+    pub generation: usize,
+    pub content: Result<PreviewContent>,
+}
+
+/// SplitView is a View displaying a story view on the left and
+/// the preview (comments or article) of the focused story on the right
+/// in a horizontal `LinearLayout`.
+///
+/// The preview pane is only displayed if the view is wide enough.
+pub struct SplitView {
+    view: LinearLayout,
+
+    mode: PreviewMode,
+    /// the id of the story whose preview is displayed (or being loaded)
+    preview_story_id: Option<u32>,
+
+    /// the generation of the latest preview request. Results of outdated requests are dropped.
+    generation: Arc<AtomicUsize>,
+    sender: std::sync::mpsc::Sender<PreviewResult>,
+    receiver: std::sync::mpsc::Receiver<PreviewResult>,
+
+    client: &'static client::HNClient,
+    cb_sink: CbSink,
+}
+
+/// the positions of the panes (and the separator between them) in the layout
+const STORY_PANE_ID: usize = 0;
+const SEPARATOR_ID: usize = 1;
+const PREVIEW_PANE_ID: usize = 2;
+
+impl SplitView {
+    pub fn new(
+        story_view: OnEventView<LinearLayout>,
+        client: &'static client::HNClient,
+        cb_sink: CbSink,
+    ) -> Self {
+        let (sender, receiver) = std::sync::mpsc::channel();
+
+        let separator = Canvas::new(())
+            .with_draw(|_, printer| {
+                printer.with_style(
+                    Style::from(config::get_config_theme().component_style.metadata),
+                    |p| p.print_vline((0, 0), p.size.y, "│"),
+                )
+            })
+            .with_required_size(|_, constraint| Vec2::new(1, constraint.y));
+
+        let view = LinearLayout::horizontal()
+            .child(story_view.full_width())
+            .child(HideableView::new(separator))
+            .child(HideableView::new(BoxedView::boxed(DummyView).full_width()));
+
+        Self {
+            view,
+            mode: PreviewMode::Comments,
+            preview_story_id: None,
+            generation: Arc::new(AtomicUsize::new(0)),
+            sender,
+            receiver,
+            client,
+            cb_sink,
+        }
+    }
+
+    fn get_story_pane_mut(&mut self) -> Option<&mut ResizedView<OnEventView<LinearLayout>>> {
+        self.view.get_child_mut(STORY_PANE_ID)?.downcast_mut()
+    }
+
+    fn get_preview_pane_mut(&mut self) -> Option<&mut HideableView<ResizedView<BoxedView>>> {
+        self.view.get_child_mut(PREVIEW_PANE_ID)?.downcast_mut()
+    }
+
+    fn set_preview(&mut self, preview: BoxedView) {
+        if let Some(pane) = self.get_preview_pane_mut() {
+            *pane.get_inner_mut().get_inner_mut() = preview;
+        }
+    }
+
+    /// gets the focused story of the story view
+    fn get_focused_story(&self) -> Option<&Story> {
+        let view = self
+            .view
+            .get_child(STORY_PANE_ID)?
+            .downcast_ref::<ResizedView<OnEventView<LinearLayout>>>()?
+            .get_inner()
+            .get_inner()
+            .get_child(1)?
+            .downcast_ref::<ResizedView<OnEventView<StoryView>>>()?
+            .get_inner()
+            .get_inner();
+        view.stories.get(view.get_focus_index())
+    }
+
+    /// focuses the story view's pane if it's not focused
+    fn focus_story_pane(&mut self) {
+        if self.view.get_focus_index() != STORY_PANE_ID {
+            self.view
+                .set_focus_index(STORY_PANE_ID)
+                .unwrap_or(EventResult::Consumed(None));
+        }
+    }
+
+    /// toggles between previewing the comments and the article of the focused story
+    pub fn toggle_preview_mode(&mut self) {
+        self.mode = match self.mode {
+            PreviewMode::Comments => PreviewMode::Article,
+            PreviewMode::Article => PreviewMode::Comments,
+        };
+        // force reloading the preview
+        self.preview_story_id = None;
+        self.focus_story_pane();
+    }
+
+    /// toggles the focus between the story view and the preview pane
+    pub fn toggle_pane_focus(&mut self) {
+        if self.view.get_focus_index() == PREVIEW_PANE_ID
+            || self.view.set_focus_index(PREVIEW_PANE_ID).is_err()
+        {
+            self.focus_story_pane();
+        }
+    }
+
+    /// requests the preview of the focused story if the focused story is changed
+    fn update_preview(&mut self) {
+        let Some(story) = self.get_focused_story() else {
+            return;
+        };
+        if self.preview_story_id == Some(story.id) {
+            return;
+        }
+        let (story_id, url) = (story.id, story.url.clone());
+
+        self.preview_story_id = Some(story_id);
+        self.focus_story_pane();
+        self.set_preview(BoxedView::boxed(TextView::new(StyledString::styled(
+            "Loading...",
+            config::get_config_theme().component_style.metadata,
+        ))));
+
+        let generation = self.generation.fetch_add(1, Ordering::SeqCst) + 1;
+
+        std::thread::spawn({
+            let latest_generation = self.generation.clone();
+            let sender = self.sender.clone();
+            let client = self.client;
+            let mode = self.mode;
+
+            // use a `cb_sink` to notify the `Cursive` renderer to re-draw the application
+            // after loading the preview
+            let cb_sink = self.cb_sink.clone();
+
+            move || {
+                std::thread::sleep(PREVIEW_DEBOUNCE_DELAY);
+                if latest_generation.load(Ordering::SeqCst) != generation {
+                    return;
+                }
+
+                let content = match mode {
+                    PreviewMode::Comments => client
+                        .get_page_data(story_id)
+                        .map(|data| PreviewContent::Comments(Box::new(data))),
+                    PreviewMode::Article => {
+                        if url.is_empty() {
+                            Err(anyhow::anyhow!("the story (id={story_id}) has no article"))
+                        } else {
+                            client.get_article(&url).map(PreviewContent::Article)
+                        }
+                    }
+                };
+                // the view may have been destroyed, ignore the sending errors
+                if sender
+                    .send(PreviewResult {
+                        generation,
+                        content,
+                    })
+                    .is_ok()
+                {
+                    // send a dummy callback to `cb_sink`
+                    cb_sink.send(Box::new(move |_| {})).unwrap_or_default();
+                }
+            }
+        });
+    }
+
+    /// tries to update the preview pane based on the results from previous preview requests
+    fn try_update_preview(&mut self) {
+        while let Ok(result) = self.receiver.try_recv() {
+            // only care about the result of the latest request
+            if result.generation != self.generation.load(Ordering::SeqCst) {
+                continue;
+            }
+
+            let (client, cb_sink) = (self.client, self.cb_sink.clone());
+            self.set_preview(BoxedView::boxed(ResultView::new(
+                result.content,
+                |content| match content {
+                    PreviewContent::Comments(data) => {
+                        BoxedView::boxed(comment_view::construct_comment_view(
+                            client,
+                            *data,
+                            None,
+                            cb_sink.clone(),
+                            false,
+                        ))
+                    }
+                    PreviewContent::Article(article) => {
+                        BoxedView::boxed(article_view::construct_article_view(client, article))
+                    }
+                },
+            )));
+        }
+    }
+
+    /// splits the view if it's wide enough, the story view's width is derived
+    /// from the configured split ratio
+    fn update_panes(&mut self, size: Vec2) {
+        let config = config::get_config();
+        let split = size.x >= config.split_view_min_width.max(3);
+
+        let story_pane_width = if split {
+            let ratio = config.split_view_ratio.clamp(0.0, 1.0);
+            SizeConstraint::Fixed(((size.x as f64 * ratio) as usize).clamp(1, size.x - 2))
+        } else {
+            SizeConstraint::Full
+        };
+        if let Some(pane) = self.get_story_pane_mut() {
+            pane.set_width(story_pane_width);
+        }
+        if let Some(separator) = self
+            .view
+            .get_child_mut(SEPARATOR_ID)
+            .and_then(|v| v.downcast_mut::<HideableView<Canvas<()>>>())
+        {
+            separator.set_visible(split);
+        }
+        if let Some(pane) = self.get_preview_pane_mut() {
+            pane.set_visible(split);
+        }
+
+        if split {
+            self.update_preview();
+        } else {
+            self.focus_story_pane();
+        }
+    }
+}
+
+impl ViewWrapper for SplitView {
+    wrap_impl!(self.view: LinearLayout);
+
+    fn wrap_layout(&mut self, size: Vec2) {
+        self.try_update_preview();
+        self.update_panes(size);
+        self.view.layout(size);
+    }
+
+    fn wrap_on_event(&mut self, e: Event) -> EventResult {
+        let split_view_keymap = config::get_split_view_keymap();
+        let split = self
+            .get_preview_pane_mut()
+            .is_some_and(|pane| pane.is_visible());
+        if split && split_view_keymap.toggle_pane_focus.has_event(&e) {
+            self.toggle_pane_focus();
+            return EventResult::Consumed(None);
+        }
+        if split && split_view_keymap.toggle_preview_mode.has_event(&e) {
+            self.toggle_preview_mode();
+            return EventResult::Consumed(None);
+        }
+
+        // mouse events are sent to the pane under the mouse cursor by the layout
+        self.view.on_event(e)
+    }
+
+    fn wrap_needs_relayout(&self) -> bool {
+        true
+    }
+}
@@ -1,8 +1,12 @@
 use super::{
-    article_view, async_view, comment_view, help_view::HasHelpView, text_view, traits::*, utils,
+    article_view, async_view, command_palette, comment_view, help_view::HasHelpView, key_sequence,
+    session, split_view::SplitView, text_view, traits::*, utils,
 };
 use crate::client::StoryNumericFilters;
 use crate::prelude::*;
+use crate::storage;
+use std::collections::HashMap;
+use unicode_width::UnicodeWidthStr;
 
 static STORY_TAGS: [&str; 5] = ["front_page", "story", "ask_hn", "show_hn", "job"];
 
@@ -10,66 +14,428 @@ static STORY_TAGS: [&str; 5] = ["front_page", "story", "ask_hn", "show_hn", "job
 /// to a particular category (top stories, newest stories, most popular stories, etc).
 pub struct StoryView {
     pub stories: Vec<Story>,
+    /// the ranks of the stories in the view. Stories hidden by the filtering rules are still
+    /// ranked, so a story's rank may differ from its position in the view.
+    ranks: Vec<usize>,
+    /// the number of loaded stories, including the hidden ones
+    num_loaded_stories: usize,
+    starting_id: usize,
+    /// the maximum length of a story's ID, which is used to align the display of the story IDs
+    max_id_len: usize,
 
     view: ScrollView<LinearLayout>,
-    raw_command: String,
+
+    loader: Option<StoryLoader>,
+    /// the ranks of stories before the last refresh, which are used to mark the rank changes
+    previous_ranks: Option<HashMap<u32, usize>>,
+    /// the end of the ranks of stories loaded by the last refresh
+    refreshed_rank_end: usize,
+    /// the stored snapshots of stories, which are used to show the stories' movement
+    /// since the last fetch. Only front page stories' snapshots are tracked.
+    snapshots: Option<storage::StorySnapshots>,
+}
+
+/// StoryLoader loads stories of a `StoryView` in the background, which is used to
+/// - append the next pages of stories when the end of the view is reached
+/// - periodically refresh the stories in the view
+struct StoryLoader {
+    client: &'static client::HNClient,
+    tag: &'static str,
+    sort_mode: client::StorySortMode,
+    numeric_filters: client::StoryNumericFilters,
+
+    /// the first page of stories in the view
+    page: usize,
+    next_page: usize,
+    infinite_scrolling: bool,
+    /// whether stories are being loaded
+    loading: bool,
+    /// whether all the stories have been loaded
+    finished: bool,
+
+    sender: std::sync::mpsc::Sender<LoaderMessage>,
+    receiver: std::sync::mpsc::Receiver<LoaderMessage>,
+    cb_sink: CbSink,
+}
+
+enum LoaderMessage {
+    /// the next page of stories
+    NextPage(Result<Vec<Story>>),
+    /// a signal to refresh the stories in the view
+    RefreshTick,
+    /// the re-fetched stories in the view
+    Refresh(Result<Vec<Story>>),
+}
+
+impl StoryLoader {
+    /// loads stories in the given pages in a separate thread, then sends the result
+    /// wrapped by `message` to the loader's channel
+    fn load(
+        &self,
+        pages: std::ops::Range<usize>,
+        message: fn(Result<Vec<Story>>) -> LoaderMessage,
+    ) {
+        std::thread::spawn({
+            let sender = self.sender.clone();
+            let cb_sink = self.cb_sink.clone();
+            let (client, tag, sort_mode, numeric_filters) =
+                (self.client, self.tag, self.sort_mode, self.numeric_filters);
+
+            move || {
+                let result = pages
+                    .map(|page| client.get_stories_by_tag(tag, sort_mode, page, numeric_filters))
+                    .collect::<Result<Vec<_>>>()
+                    .map(|pages| pages.into_iter().flatten().collect());
+                // the view may have been destroyed, ignore the sending errors
+                if sender.send(message(result)).is_ok() {
+                    // send a dummy callback to `cb_sink` to re-draw the application
+                    cb_sink.send(Box::new(move |_| {})).unwrap_or_default();
+                }
+            }
+        });
+    }
 }
 
 impl ViewWrapper for StoryView {
     wrap_impl!(self.view: ScrollView<LinearLayout>);
+
+    fn wrap_layout(&mut self, size: Vec2) {
+        let refreshed = self.try_update_stories();
+        self.view.layout(size);
+        if refreshed {
+            self.view.scroll_to_important_area();
+        }
+    }
 }
 
 impl StoryView {
     pub fn new(stories: Vec<Story>, starting_id: usize) -> Self {
-        StoryView {
-            view: Self::construct_story_view(&stories, starting_id),
-            stories,
-            raw_command: String::new(),
+        let mut view = StoryView {
+            view: LinearLayout::vertical().scrollable(),
+            stories: vec![],
+            ranks: vec![],
+            num_loaded_stories: 0,
+            starting_id,
+            max_id_len: 0,
+            loader: None,
+            previous_ranks: None,
+            refreshed_rank_end: 0,
+            snapshots: None,
+        };
+        view.append_stories(stories);
+        view
+    }
+
+    /// sets up a story loader to load stories in the background based on the application's config
+    fn set_up_loader(
+        &mut self,
+        client: &'static client::HNClient,
+        tag: &'static str,
+        sort_mode: client::StorySortMode,
+        page: usize,
+        numeric_filters: client::StoryNumericFilters,
+        cb_sink: CbSink,
+    ) {
+        let config = config::get_config();
+        let (sender, receiver) = std::sync::mpsc::channel();
+
+        if tag == "front_page" {
+            // re-render the stories to show their movement since the last fetch
+            self.snapshots = Some(storage::get_story_snapshots());
+            self.render_stories(0);
+            self.update_snapshots();
+        }
+
+        if let Some(interval) = config.story_refresh_interval {
+            // periodically send a signal to refresh the stories
+            // until the view is destroyed, i.e the receiver is dropped
+            std::thread::spawn({
+                let sender = sender.clone();
+                let cb_sink = cb_sink.clone();
+                let interval = std::time::Duration::from_secs(interval);
+                move || loop {
+                    std::thread::sleep(interval);
+                    if sender.send(LoaderMessage::RefreshTick).is_err()
+                        || cb_sink.send(Box::new(move |_| {})).is_err()
+                    {
+                        break;
+                    }
+                }
+            });
+        }
+
+        self.loader = Some(StoryLoader {
+            client,
+            tag,
+            sort_mode,
+            numeric_filters,
+            page,
+            next_page: page + 1,
+            infinite_scrolling: config.use_infinite_scrolling,
+            loading: false,
+            finished: false,
+            sender,
+            receiver,
+            cb_sink,
+        });
+    }
+
+    /// loads the next page of stories in the background if the infinite scrolling is enabled
+    fn load_next_page(&mut self) {
+        let loader = match self.loader.as_mut() {
+            Some(loader) if loader.infinite_scrolling && !loader.loading && !loader.finished => {
+                loader
+            }
+            _ => return,
+        };
+        loader.loading = true;
+
+        let page = loader.next_page;
+        loader.load(page..page + 1, LoaderMessage::NextPage);
+    }
+
+    /// re-fetches all the stories in the view in the background
+    fn refresh(&mut self) {
+        let loader = match self.loader.as_mut() {
+            Some(loader) if !loader.loading => loader,
+            _ => return,
+        };
+        loader.loading = true;
+
+        let pages = loader.page..loader.next_page;
+        loader.load(pages, LoaderMessage::Refresh);
+    }
+
+    /// tries to update the view with the stories loaded by the story loader.
+    ///
+    /// Returns `true` if the view is refreshed.
+    fn try_update_stories(&mut self) -> bool {
+        let mut refreshed = false;
+        while let Some(message) = self
+            .loader
+            .as_ref()
+            .and_then(|l| l.receiver.try_recv().ok())
+        {
+            let Some(loader) = self.loader.as_mut() else {
+                break;
+            };
+            match message {
+                LoaderMessage::NextPage(result) => {
+                    loader.loading = false;
+                    match result {
+                        Ok(stories) => {
+                            loader.next_page += 1;
+                            loader.finished = stories.is_empty();
+                            self.append_stories(stories);
+                        }
+                        Err(err) => {
+                            warn!(
+                                "failed to load more stories (tag={}, page={}): {}",
+                                loader.tag, loader.next_page, err
+                            );
+                        }
+                    }
+                }
+                LoaderMessage::RefreshTick => self.refresh(),
+                LoaderMessage::Refresh(result) => {
+                    loader.loading = false;
+                    match result {
+                        Ok(stories) => {
+                            self.replace_stories(stories);
+                            refreshed = true;
+                        }
+                        Err(err) => {
+                            warn!("failed to refresh stories (tag={}): {}", loader.tag, err);
+                        }
+                    }
+                }
+            }
         }
+        refreshed
+    }
+
+    /// replaces the stories in the view with the refreshed stories,
+    /// marking the rank changes and preserving the focused story
+    fn replace_stories(&mut self, stories: Vec<Story>) {
+        let focus_id = self.get_focus_index();
+        let focused_story_id = self.stories.get(focus_id).map(|story| story.id);
+
+        self.previous_ranks = Some(
+            self.stories
+                .iter()
+                .zip(&self.ranks)
+                .map(|(story, rank)| (story.id, *rank))
+                .collect(),
+        );
+        self.refreshed_rank_end = self.starting_id + stories.len();
+
+        self.get_inner_list_mut().clear();
+        self.stories.clear();
+        self.ranks.clear();
+        self.num_loaded_stories = 0;
+        self.append_stories(stories);
+        self.update_snapshots();
+
+        let new_focus_id = self
+            .stories
+            .iter()
+            .position(|story| Some(story.id) == focused_story_id)
+            .unwrap_or_else(|| std::cmp::min(focus_id, self.len().saturating_sub(1)));
+        self.get_inner_list_mut()
+            .set_focus_index(new_focus_id)
+            .unwrap_or(EventResult::Consumed(None));
     }
 
-    fn construct_story_view(stories: &[Story], starting_id: usize) -> ScrollView<LinearLayout> {
+    /// appends stories to the view, numbering them continuously.
+    ///
+    /// Stories matching the filtering rules are hidden or dimmed depending on the config.
+    fn append_stories(&mut self, stories: Vec<Story>) {
+        let dim_filtered_items = config::get_config().filter.dim_filtered_items;
+
+        // stories are ranked before being filtered, so hiding a story doesn't affect other stories' ranks
+        let starting_rank = self.starting_id + self.num_loaded_stories;
+        self.num_loaded_stories += stories.len();
+        let (ranks, stories): (Vec<_>, Vec<_>) = stories
+            .into_iter()
+            .enumerate()
+            .filter(|(_, story)| dim_filtered_items || !story.is_filtered())
+            .map(|(i, story)| (starting_rank + i, story))
+            .unzip();
+
+        let start_id = self.stories.len();
+        self.stories.extend(stories);
+        self.ranks.extend(ranks);
+
         // Determine the maximum length of a story's ID.
         // This maximum length is used to align the display of the story IDs.
-        let max_id_len = {
-            let max_id = starting_id + stories.len() + 1;
-            let mut width = 0;
-            let mut pw = 1;
-            while pw <= max_id {
-                pw *= 10;
-                width += 1;
+        // If the length changes, the rendered stories are re-rendered to be aligned with the new ones.
+        let max_id_len = (self.starting_id + self.stories.len()).to_string().len();
+        if max_id_len != self.max_id_len {
+            self.max_id_len = max_id_len;
+            self.render_stories(0);
+        } else {
+            self.render_stories(start_id);
+        }
+    }
+
+    /// renders the stories starting from the `start_id`-th story,
+    /// replacing the stories' rendered items if exist
+    fn render_stories(&mut self, start_id: usize) {
+        let focus_id = self.get_focus_index();
+        let list = self.get_inner_list_mut();
+        while list.len() > start_id {
+            list.remove_child(list.len() - 1);
+        }
+
+        for id in start_id..self.stories.len() {
+            let story = &self.stories[id];
+            // initialize the story text with its ID
+            let mut story_text = StyledString::styled(
+                format!("{1:>0$}. ", self.max_id_len, self.starting_id + id + 1),
+                config::get_config_theme().component_style.metadata,
+            );
+            story_text.append(Self::get_story_text(
+                self.max_id_len,
+                story,
+                self.get_story_movement_text(story, self.ranks[id]),
+            ));
+            if story.is_filtered() {
+                story_text = StyledString::styled(
+                    story_text.source(),
+                    config::get_config_theme().component_style.filtered_item,
+                );
             }
 
-            width
+            self.add_item(text_view::TextView::new(story_text));
+        }
+
+        if focus_id >= start_id && focus_id < self.len() {
+            self.get_inner_list_mut()
+                .set_focus_index(focus_id)
+                .unwrap_or(EventResult::Consumed(None));
+        }
+    }
+
+    /// records snapshots of the stories in the view if the stories' snapshots are tracked
+    fn update_snapshots(&mut self) {
+        if self.snapshots.is_none() {
+            return;
+        }
+        storage::add_story_snapshots(
+            self.stories
+                .iter()
+                .zip(&self.ranks)
+                .map(|(story, rank)| (story.id, *rank, story.points)),
+            crate::utils::get_current_unix_time(),
+        );
+        self.snapshots = Some(storage::get_story_snapshots());
+    }
+
+    /// Get the text describing the movement of a story since the last fetch,
+    /// which includes the story's rank change and score velocity (if available)
+    fn get_story_movement_text(&self, story: &Story, rank: usize) -> Option<StyledString> {
+        let component_style = &config::get_config_theme().component_style;
+
+        // the story's previous rank is based on the last refresh
+        // or the stored snapshots if the view hasn't been refreshed
+        let previous_rank = match (&self.previous_ranks, &self.snapshots) {
+            // stories appended after the last refresh are not marked as new
+            (Some(_), _) if rank >= self.refreshed_rank_end => None,
+            (Some(ranks), _) => Some(ranks.get(&story.id).copied()),
+            (None, Some(snapshots)) if !snapshots.is_empty() => {
+                Some(snapshots.latest(story.id).map(|s| s.rank))
+            }
+            _ => None,
         };
 
-        LinearLayout::vertical()
-            .with(|s| {
-                stories.iter().enumerate().for_each(|(i, story)| {
-                    // initialize the story text with its ID
-                    let mut story_text = StyledString::styled(
-                        format!("{1:>0$}. ", max_id_len, starting_id + i + 1),
-                        config::get_config_theme().component_style.metadata,
-                    );
-                    story_text.append(Self::get_story_text(max_id_len, story));
-
-                    s.add_child(text_view::TextView::new(story_text));
-                })
-            })
-            .scrollable()
+        let mut text = StyledString::new();
+        match previous_rank {
+            None => {}
+            Some(None) => text.append_styled(" | new", component_style.new_story),
+            Some(Some(previous_rank)) if previous_rank > rank => text.append_styled(
+                format!(" | ↑{}", previous_rank - rank),
+                component_style.rank_up,
+            ),
+            Some(Some(previous_rank)) if previous_rank < rank => text.append_styled(
+                format!(" | ↓{}", rank - previous_rank),
+                component_style.rank_down,
+            ),
+            Some(Some(_)) => {}
+        }
+
+        if let Some(velocity) = self.snapshots.as_ref().and_then(|snapshots| {
+            snapshots.points_velocity(
+                story.id,
+                story.points,
+                crate::utils::get_current_unix_time(),
+            )
+        }) {
+            text.append_styled(
+                format!(" | {:+} pts/h", velocity.round() as i64),
+                component_style.metadata,
+            );
+        }
+
+        if text.is_empty() {
+            None
+        } else {
+            Some(text)
+        }
     }
 
     /// Get the text summarizing basic information about a story
-    fn get_story_text(max_id_len: usize, story: &Story) -> StyledString {
+    fn get_story_text(
+        max_id_len: usize,
+        story: &Story,
+        movement: Option<StyledString>,
+    ) -> StyledString {
         let mut story_text = story.styled_title();
 
-        if let Ok(url) = url::Url::parse(&story.url) {
-            if let Some(domain) = url.domain() {
-                story_text.append_styled(
-                    format!(" ({domain})"),
-                    config::get_config_theme().component_style.link,
-                );
-            }
+        if let Some(domain) = crate::utils::get_url_domain(&story.url) {
+            story_text.append_styled(
+                format!(" ({domain})"),
+                config::get_config_theme().component_style.link,
+            );
         }
 
         story_text.append_plain("\n");
@@ -88,6 +454,9 @@ impl StoryView {
             ),
             config::get_config_theme().component_style.metadata,
         );
+        if let Some(movement) = movement {
+            story_text.append(movement);
+        }
         story_text
     }
 
@@ -108,6 +477,10 @@ impl ListViewContainer for StoryView {
 
         // enable auto-scrolling when changing the focused index of the view
         self.scroll(direction);
+
+        if new_id + 1 >= self.len() {
+            self.load_next_page();
+        }
     }
 }
 
@@ -128,27 +501,23 @@ pub fn construct_story_main_view(
     client: &'static client::HNClient,
     starting_id: usize,
 ) -> OnEventView<StoryView> {
-    let is_suffix_key =
-        |c: &Event| -> bool { config::get_story_view_keymap().goto_story.has_event(c) };
-
     let story_view_keymap = config::get_story_view_keymap().clone();
 
-    OnEventView::new(StoryView::new(stories, starting_id))
-        // number parsing
-        .on_pre_event_inner(EventTrigger::from_fn(|_| true), move |s, e| {
-            match *e {
-                Event::Char(c) if c.is_ascii_digit() => {
-                    s.raw_command.push(c);
-                }
-                _ => {
-                    if !is_suffix_key(e) {
-                        s.raw_command.clear();
-                    }
-                }
-            };
+    let goto_story_comment_view = move |s: &mut StoryView| {
+        // the story struct hasn't had any comments inside yet,
+        // so it can be cloned without greatly affecting performance
+        let item_id = s.stories.get(s.get_focus_index())?.id;
+        Some(EventResult::with_cb({
+            move |s| {
+                comment_view::construct_and_add_new_comment_view(s, client, item_id, None, false)
+            }
+        }))
+    };
 
-            // don't allow the inner `LinearLayout` child view to handle the event
-            // because of its pre-defined `on_event` function
+    OnEventView::new(StoryView::new(stories, starting_id))
+        // don't allow the inner `LinearLayout` child view to handle the event
+        // because of its pre-defined `on_event` function
+        .on_pre_event_inner(EventTrigger::from_fn(|_| true), |_, _| {
             Some(EventResult::Ignored)
         })
         // story navigation shortcuts
@@ -165,25 +534,36 @@ pub fn construct_story_main_view(
             s.set_focus_index(id + 1)
         })
         .on_pre_event_inner(story_view_keymap.goto_story_comment_view, move |s, _| {
-            let id = s.get_focus_index();
-            // the story struct hasn't had any comments inside yet,
-            // so it can be cloned without greatly affecting performance
-            let item_id = s.stories[id].id;
-            Some(EventResult::with_cb({
-                move |s| comment_view::construct_and_add_new_comment_view(s, client, item_id, false)
+            goto_story_comment_view(s)
+        })
+        .on_pre_event_inner(story_view_keymap.open_story_in_new_tab, move |s, _| {
+            let item_id = s.stories.get(s.get_focus_index())?.id;
+            Some(EventResult::with_cb(move |s| {
+                session::open_new_tab(s);
+                comment_view::construct_and_add_new_comment_view(s, client, item_id, None, false)
             }))
         })
+        .on_pre_event_inner(story_view_keymap.open_article_in_new_tab, move |s, _| {
+            let url = s.stories.get(s.get_focus_index())?.url.clone();
+            if !url.is_empty() {
+                Some(EventResult::with_cb(move |s| {
+                    session::open_new_tab(s);
+                    article_view::construct_and_add_new_article_view(client, s, &url)
+                }))
+            } else {
+                Some(EventResult::Consumed(None))
+            }
+        })
         // open external link shortcuts
         .on_pre_event_inner(story_view_keymap.open_article_in_browser, move |s, _| {
-            let id = s.get_focus_index();
-            utils::open_url_in_browser(s.stories[id].get_url().as_ref());
+            let story = s.stories.get(s.get_focus_index())?;
+            utils::open_url_in_browser(story.get_url().as_ref());
             Some(EventResult::Consumed(None))
         })
         .on_pre_event_inner(
             story_view_keymap.open_article_in_article_view,
             move |s, _| {
-                let id = s.get_focus_index();
-                let url = s.stories[id].url.clone();
+                let url = s.stories.get(s.get_focus_index())?.url.clone();
                 if !url.is_empty() {
                     Some(EventResult::with_cb({
                         move |s| article_view::construct_and_add_new_article_view(client, s, &url)
@@ -194,31 +574,71 @@ pub fn construct_story_main_view(
             },
         )
         .on_pre_event_inner(story_view_keymap.open_story_in_browser, move |s, _| {
-            let url = s.stories[s.get_focus_index()].story_url();
+            let url = s.stories.get(s.get_focus_index())?.story_url();
             utils::open_url_in_browser(&url);
             Some(EventResult::Consumed(None))
         })
         .on_pre_event_inner(story_view_keymap.goto_story, move |s, _| {
-            match s.raw_command.parse::<usize>() {
-                Ok(number) => {
-                    s.raw_command.clear();
-                    if number < starting_id + 1 {
-                        return None;
-                    }
-                    let number = number - 1 - starting_id;
-                    if number < s.len() {
-                        s.set_focus_index(number).unwrap();
-                        Some(EventResult::Consumed(None))
-                    } else {
-                        None
-                    }
-                }
-                Err(_) => None,
+            let number = key_sequence::get_count()?;
+            if number < starting_id + 1 {
+                return None;
+            }
+            let number = number - 1 - starting_id;
+            if number < s.len() {
+                s.set_focus_index(number).unwrap();
+                Some(EventResult::Consumed(None))
+            } else {
+                None
             }
         })
+        .on_mouse_events(goto_story_comment_view)
         .on_scroll_events()
 }
 
+/// Get the static story tag matching a given tag
+pub fn get_story_tag(tag: &str) -> Option<&'static str> {
+    STORY_TAGS.iter().find(|t| **t == tag).copied()
+}
+
+pub(super) fn get_sort_mode_desc(sort_mode: client::StorySortMode) -> &'static str {
+    match sort_mode {
+        client::StorySortMode::None => "",
+        client::StorySortMode::Date => " (by_date)",
+        client::StorySortMode::Points => " (by_point)",
+    }
+}
+
+fn get_story_tag_label(
+    i: usize,
+    item: &str,
+    tag: &str,
+    sort_mode: client::StorySortMode,
+) -> String {
+    if item == tag {
+        format!("{}.{}{}", i + 1, item, get_sort_mode_desc(sort_mode))
+    } else {
+        format!("{}.{}", i + 1, item)
+    }
+}
+
+/// Get the story tag whose label is displayed at the `x` column of the story view's title bar
+fn get_story_tag_at(
+    x: usize,
+    tag: &'static str,
+    sort_mode: client::StorySortMode,
+) -> Option<&'static str> {
+    let mut pos = "[Y] Hacker News".width();
+    for (i, item) in STORY_TAGS.iter().enumerate() {
+        pos += " | ".width();
+        let width = get_story_tag_label(i, item, tag, sort_mode).width();
+        if (pos..pos + width).contains(&x) {
+            return Some(item);
+        }
+        pos += width;
+    }
+    None
+}
+
 fn get_story_view_title_bar(tag: &'static str, sort_mode: client::StorySortMode) -> impl View {
     let style = config::get_config_theme().component_style.title_bar;
     let mut title = StyledString::styled(
@@ -232,18 +652,13 @@ fn get_story_view_title_bar(tag: &'static str, sort_mode: client::StorySortMode)
     for (i, item) in STORY_TAGS.iter().enumerate() {
         title.append_styled(" | ", style);
         if *item == tag {
-            let sort_mode_desc = match sort_mode {
-                client::StorySortMode::None => "",
-                client::StorySortMode::Date => " (by_date)",
-                client::StorySortMode::Points => " (by_point)",
-            };
             title.append_styled(
-                format!("{}.{}{}", i + 1, item, sort_mode_desc),
+                get_story_tag_label(i, item, tag, sort_mode),
                 Style::from(style)
                     .combine(config::get_config_theme().component_style.current_story_tag),
             );
         } else {
-            title.append_styled(format!("{}.{}", i + 1, item), style);
+            title.append_styled(get_story_tag_label(i, item, tag, sort_mode), style);
         }
     }
     title.append_styled(" | ", style);
@@ -265,9 +680,15 @@ pub fn construct_story_view(
     sort_mode: client::StorySortMode,
     page: usize,
     numeric_filters: client::StoryNumericFilters,
+    cb_sink: CbSink,
 ) -> impl View {
-    let starting_id = client::STORY_LIMIT * page;
-    let main_view = construct_story_main_view(stories, client, starting_id).full_height();
+    let split_view_cb_sink = cb_sink.clone();
+    let starting_id = client.story_limit() * page;
+    let mut main_view = construct_story_main_view(stories, client, starting_id);
+    main_view
+        .get_inner_mut()
+        .set_up_loader(client, tag, sort_mode, page, numeric_filters, cb_sink);
+    let main_view = main_view.full_height();
 
     let mut view = LinearLayout::vertical()
         .child(get_story_view_title_bar(tag, sort_mode))
@@ -287,10 +708,19 @@ pub fn construct_story_view(
     // some of the story keymaps need to be handled here instead of by the main view like
     // for comment views or article views.
 
-    OnEventView::new(view)
+    let view = OnEventView::new(view)
         .on_pre_event(config::get_global_keymap().open_help_dialog.clone(), |s| {
             s.add_layer(StoryView::construct_on_event_help_view())
         })
+        .on_pre_event(
+            config::get_global_keymap().open_command_palette.clone(),
+            move |s| {
+                s.add_layer(command_palette::construct_command_palette(
+                    client,
+                    StoryView::command_groups(),
+                ))
+            },
+        )
         .on_pre_event(story_view_keymap.cycle_sort_mode, move |s| {
             // disable "search_by_date" for front_page stories
             if tag == "front_page" {
@@ -306,6 +736,37 @@ pub fn construct_story_view(
                 true,
             );
         })
+        // switch to the story view of the clicked tag in the title bar
+        .on_pre_event_inner(EventTrigger::mouse(), move |_, e| {
+            let Event::Mouse {
+                event: MouseEvent::Press(MouseButton::Left),
+                position,
+                offset,
+            } = *e
+            else {
+                return None;
+            };
+            let pos = position.checked_sub(offset)?;
+            if pos.y != 0 {
+                return None;
+            }
+            let clicked_tag = get_story_tag_at(pos.x, tag, sort_mode)?;
+            Some(EventResult::with_cb(move |s| {
+                construct_and_add_new_story_view(
+                    s,
+                    client,
+                    clicked_tag,
+                    if clicked_tag == "story" || clicked_tag == "job" {
+                        client::StorySortMode::Date
+                    } else {
+                        client::StorySortMode::None
+                    },
+                    0,
+                    StoryNumericFilters::default(),
+                    false,
+                )
+            }))
+        })
         // story tag navigation
         .on_pre_event(story_view_keymap.next_story_tag, move |s| {
             let next_tag = STORY_TAGS[(current_tag_pos + 1) % STORY_TAGS.len()];
@@ -363,7 +824,14 @@ pub fn construct_story_view(
                 numeric_filters,
                 true,
             );
-        })
+        });
+
+    // display the focused story's preview next to the story view in a split view
+    if config::get_config().use_split_view {
+        BoxedView::boxed(SplitView::new(view, client, split_view_cb_sink))
+    } else {
+        BoxedView::boxed(view)
+    }
 }
 
 /// Retrieve a list of stories satisfying some conditions and construct a story view displaying them.
@@ -381,5 +849,11 @@ pub fn construct_and_add_new_story_view(
     if pop_layer {
         s.pop_layer();
     }
-    s.screen_mut().add_transparent_layer(Layer::new(async_view));
+    let session_view = storage::SessionView::StoryView {
+        tag: tag.to_string(),
+        sort_mode,
+        page,
+        numeric_filters,
+    };
+    session::add_session_layer(s, session_view, Layer::new(async_view));
 }
@@ -8,12 +8,20 @@ pub struct TextView {
     width: usize,
     size_cache: Option<XY<SizeCache>>,
     padding: TextPadding,
+    /// links referenced by the `[N]` link markers in the text
+    links: Vec<String>,
+    /// the link markers in the text, which can be clicked to open their links
+    link_markers: Vec<crate::parser::LinkMarker>,
 }
 
 #[derive(Default)]
 pub struct TextPadding {
     pub left: Option<StyledPaddingChar>,
     pub top: Option<StyledPaddingChar>,
+    /// tree guides printed before the left padding, each of which is followed by a space
+    pub guides: Vec<StyledPaddingChar>,
+    /// a character replacing the last tree guide in the first row
+    pub branch: Option<StyledPaddingChar>,
 }
 
 /// EditableTextView is a View displaying an editable text with cursor
@@ -37,9 +45,26 @@ impl TextView {
             width: 0,
             size_cache: None,
             padding: TextPadding::default(),
+            links: Vec::new(),
+            link_markers: Vec::new(),
         }
     }
 
+    /// sets the links referenced by the text's link markers and the markers' positions,
+    /// the links can be opened by clicking the markers
+    pub fn links(self, links: Vec<String>, link_markers: Vec<crate::parser::LinkMarker>) -> Self {
+        Self {
+            links,
+            link_markers,
+            ..self
+        }
+    }
+
+    /// updates the link markers' positions after the text's content is changed
+    pub fn set_link_markers(&mut self, link_markers: Vec<crate::parser::LinkMarker>) {
+        self.link_markers = link_markers;
+    }
+
     pub fn padding(self, padding: TextPadding) -> Self {
         Self {
             padding,
@@ -75,6 +100,41 @@ impl TextView {
         let row_width = self.width.saturating_sub(self.padding.width());
         self.rows = lines::spans::LinesIterator::new(&self.content, row_width).collect();
     }
+
+    /// gets the number of the link marker at a given position (relative to the view)
+    fn get_link_number_at(&self, pos: Vec2) -> Option<usize> {
+        let row = self.rows.get(pos.y.checked_sub(self.padding.height())?)?;
+        let x = pos.x.checked_sub(self.padding.width())?;
+
+        // the content and the offset of each span in the text's content
+        let spans = self
+            .content
+            .spans()
+            .scan(0, |offset, span| {
+                let start = *offset;
+                *offset += span.content.len();
+                Some((span.content, start))
+            })
+            .collect::<Vec<_>>();
+
+        // find the offset of the character displayed at the given column
+        let mut width = 0;
+        for segment in &row.segments {
+            let (content, span_offset) = spans[segment.span_id];
+            for (i, c) in content[segment.start..segment.end].char_indices() {
+                width += c.width().unwrap_or_default();
+                if width > x {
+                    let offset = span_offset + segment.start + i;
+                    return self
+                        .link_markers
+                        .iter()
+                        .find(|m| m.range.contains(&offset))
+                        .map(|m| m.number);
+                }
+            }
+        }
+        None
+    }
 }
 
 impl View for TextView {
@@ -87,10 +147,22 @@ impl View for TextView {
                 });
             }
 
-            self.rows.iter().enumerate().for_each(|(y, row)| {
-                let y = y + usize::from(self.padding.top.is_some());
+            self.rows.iter().enumerate().for_each(|(row_id, row)| {
+                let y = row_id + usize::from(self.padding.top.is_some());
                 let mut x: usize = 0;
 
+                // print the tree guides
+                for (i, g) in self.padding.guides.iter().enumerate() {
+                    let g = match self.padding.branch {
+                        Some(ref b) if row_id == 0 && i + 1 == self.padding.guides.len() => b,
+                        _ => g,
+                    };
+                    printer.with_style(g.1, |printer| {
+                        printer.print((x, y), &format!("{} ", g.0));
+                        x += g.width() + 1;
+                    });
+                }
+
                 // print the left padding
                 if let Some(ref p) = self.padding.left {
                     printer.with_style(p.1, |printer| {
@@ -128,6 +200,26 @@ impl View for TextView {
         self.size_cache.is_none()
     }
 
+    fn on_event(&mut self, e: Event) -> EventResult {
+        match e {
+            Event::Mouse {
+                event: MouseEvent::Press(MouseButton::Left),
+                position,
+                offset,
+            } if !self.link_markers.is_empty() => {
+                let Some(number) = position
+                    .checked_sub(offset)
+                    .and_then(|pos| self.get_link_number_at(pos))
+                else {
+                    return EventResult::Ignored;
+                };
+                super::utils::open_ith_link_in_browser(&self.links, number)
+                    .unwrap_or(EventResult::Ignored)
+            }
+            _ => EventResult::Ignored,
+        }
+    }
+
     fn take_focus(&mut self, _: direction::Direction) -> Result<EventResult, CannotFocus> {
         Ok(EventResult::Consumed(None))
     }
@@ -155,6 +247,12 @@ impl EditableTextView {
         self.text.clone()
     }
 
+    pub fn set_text(&mut self, text: &str) {
+        self.text = text.to_string();
+        self.cursor = self.text.len();
+        self.view.set_content(self.get_content());
+    }
+
     pub fn add_char(&mut self, c: char) {
         self.text.insert(self.cursor, c);
         self.cursor += 1;
@@ -239,8 +337,17 @@ impl TextPadding {
         }
     }
 
+    pub fn guides(self, guides: Vec<StyledPaddingChar>, branch: Option<StyledPaddingChar>) -> Self {
+        Self {
+            guides,
+            branch,
+            ..self
+        }
+    }
+
     pub fn width(&self) -> usize {
-        self.left.as_ref().map(|p| p.width()).unwrap_or_default()
+        self.guides.iter().map(|g| g.width() + 1).sum::<usize>()
+            + self.left.as_ref().map(|p| p.width()).unwrap_or_default()
     }
 
     pub fn height(&self) -> usize {
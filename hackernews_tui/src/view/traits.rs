@@ -115,6 +115,64 @@ where
     }
 }
 
+/// the maximum interval between two clicks of a double-click
+const DOUBLE_CLICK_INTERVAL: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// A trait defines the `on_mouse_events` method that adds
+/// callbacks to handle mouse events for the `self` View.
+pub trait OnMouseEventView<T> {
+    /// adds callbacks handling mouse events.
+    ///
+    /// Clicking an item focuses it, and double-clicking an item calls `on_double_click`.
+    fn on_mouse_events<F>(self, on_double_click: F) -> Self
+    where
+        F: Fn(&mut T) -> Option<EventResult> + 'static;
+}
+
+impl<T> OnMouseEventView<T> for OnEventView<T>
+where
+    T: ListViewContainer + ScrollViewContainer,
+{
+    fn on_mouse_events<F>(self, on_double_click: F) -> Self
+    where
+        F: Fn(&mut T) -> Option<EventResult> + 'static,
+    {
+        // the focused item and the time of the last click
+        let last_click = std::cell::Cell::new(None::<(usize, std::time::Instant)>);
+
+        // Mouse events are forwarded to the inner scroll view, which focuses the clicked item.
+        // This is needed as list views don't allow their children to handle events.
+        self.on_pre_event_inner(EventTrigger::mouse(), move |s, e| {
+            let old_id = s.get_focus_index();
+            let result = s.get_inner_scroll_view_mut().on_event(e.clone());
+
+            if !matches!(
+                e,
+                Event::Mouse {
+                    event: MouseEvent::Press(MouseButton::Left),
+                    ..
+                }
+            ) {
+                return Some(result);
+            }
+
+            let new_id = s.get_focus_index();
+            if new_id != old_id {
+                s.on_set_focus_index(old_id, new_id);
+            }
+
+            let now = std::time::Instant::now();
+            match last_click.replace(Some((new_id, now))) {
+                Some((id, time)) if id == new_id && now - time <= DOUBLE_CLICK_INTERVAL => {
+                    last_click.set(None);
+                    on_double_click(s).or(Some(result))
+                }
+                _ => Some(result),
+            }
+        })
+    }
+}
+
 /// A trait defines the `scroll` method that scrolls the `self` view to
 /// the inner view's important area.
 ///
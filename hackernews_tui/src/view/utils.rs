@@ -1,4 +1,4 @@
-use super::{article_view, help_view};
+use super::{article_view, help_view, key_sequence};
 use crate::prelude::*;
 
 /// Construct a simple footer view
@@ -14,6 +14,7 @@ pub fn construct_footer_view<T: help_view::HasHelpView>() -> impl View {
         )
         .child(
             LinearLayout::horizontal()
+                .child(key_sequence::PendingKeysView)
                 .child(Button::new_raw(
                     format!("[{}: help] ", config::get_global_keymap().open_help_dialog),
                     |s| s.add_layer(T::construct_on_event_help_view()),
@@ -29,6 +30,16 @@ pub fn construct_footer_view<T: help_view::HasHelpView>() -> impl View {
         )
 }
 
+/// Construct a dialog displaying a message, which can be closed using the `close_dialog` shortcut
+pub fn construct_info_dialog(title: &str, content: impl Into<StyledString>) -> impl View {
+    OnEventView::new(Dialog::info(content).title(title)).on_pre_event(
+        config::get_global_keymap().close_dialog.clone(),
+        |s| {
+            s.pop_layer();
+        },
+    )
+}
+
 /// Construct a view's title bar
 pub fn construct_view_title_bar(desc: &str) -> impl View {
     let style = config::get_config_theme().component_style.title_bar;